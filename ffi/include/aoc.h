@@ -0,0 +1,64 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* generated by cbindgen from src/lib.rs, tests/header.rs checks it's up to date */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * The answer is in the buffer.
+ */
+#define AOC_OK 0
+
+/**
+ * The answer didn't fit in the buffer, which holds as much of it as did.
+ */
+#define AOC_BUFFER_TOO_SMALL 1
+
+/**
+ * A null pointer where there should be data, or a part other than 1 or 2.
+ */
+#define AOC_BAD_ARGUMENT 2
+
+/**
+ * The input isn't UTF-8.
+ */
+#define AOC_BAD_INPUT 3
+
+/**
+ * There's no solution for that day or part.
+ */
+#define AOC_NOT_FOUND 4
+
+/**
+ * The solution failed, usually because of a mistake in the input.
+ */
+#define AOC_FAILED 5
+
+/**
+ * The solution panicked, which is a bug.
+ */
+#define AOC_PANICKED 6
+
+/**
+ * Solves a part of a day with its default implementation.
+ *
+ * Returns one of the AOC_ status codes. Whatever the status, the buffer
+ * gets a nul-terminated string, cut short to fit if need be: the answer on
+ * AOC_OK, or else what went wrong, with the line and column for mistakes in
+ * the input. It's UTF-8 even when cut short.
+ *
+ * # Safety
+ *
+ * `input` must point to `input_len` readable bytes, and `out` to `out_len`
+ * writable ones. Either may be null if its length is 0.
+ */
+int32_t aoc_solve(uint32_t day,
+                  uint32_t part,
+                  const uint8_t *input,
+                  size_t input_len,
+                  char *out,
+                  size_t out_len);
+
+#endif /* AOC_H */
@@ -0,0 +1,95 @@
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+use std::str;
+
+use advent_of_code_2021::days;
+use advent_of_code_2021::outcome::panic_message;
+use advent_of_code_2021::solution::Part;
+
+// the solvers for c, or anything else that can call it. the doc comments end
+// up in include/aoc.h, which tests/header.rs checks is up to date.
+
+/// The answer is in the buffer.
+pub const AOC_OK: i32 = 0;
+/// The answer didn't fit in the buffer, which holds as much of it as did.
+pub const AOC_BUFFER_TOO_SMALL: i32 = 1;
+/// A null pointer where there should be data, or a part other than 1 or 2.
+pub const AOC_BAD_ARGUMENT: i32 = 2;
+/// The input isn't UTF-8.
+pub const AOC_BAD_INPUT: i32 = 3;
+/// There's no solution for that day or part.
+pub const AOC_NOT_FOUND: i32 = 4;
+/// The solution failed, usually because of a mistake in the input.
+pub const AOC_FAILED: i32 = 5;
+/// The solution panicked, which is a bug.
+pub const AOC_PANICKED: i32 = 6;
+
+/// Solves a part of a day with its default implementation.
+///
+/// Returns one of the AOC_ status codes. Whatever the status, the buffer
+/// gets a nul-terminated string, cut short to fit if need be: the answer on
+/// AOC_OK, or else what went wrong, with the line and column for mistakes in
+/// the input. It's UTF-8 even when cut short.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, and `out` to `out_len`
+/// writable ones. Either may be null if its length is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(day: u32, part: u32, input: *const u8, input_len: usize,
+		out: *mut c_char, out_len: usize) -> i32 {
+	let out = match (out.is_null(), out_len) {
+		(_, 0) => &mut [][..],
+		(true, _) => return AOC_BAD_ARGUMENT,
+		(false, _) => slice::from_raw_parts_mut(out as *mut u8, out_len),
+	};
+	let input = match (input.is_null(), input_len) {
+		(_, 0) => &[][..],
+		(true, _) => return write(out, AOC_BAD_ARGUMENT, "input is null"),
+		(false, _) => slice::from_raw_parts(input, input_len),
+	};
+
+	// unwinding into c is undefined, so a panic is just another status
+	match panic::catch_unwind(AssertUnwindSafe(|| solve(day, part, input))) {
+		Ok(Ok(answer)) => write(out, AOC_OK, &answer),
+		Ok(Err((status, message))) => write(out, status, &message),
+		Err(payload) => write(out, AOC_PANICKED, &format!("panicked: {}", panic_message(&*payload))),
+	}
+}
+
+fn solve(day: u32, part: u32, input: &[u8]) -> Result<String, (i32, String)> {
+	let part = match part {
+		1 => Part::One,
+		2 => Part::Two,
+		_ => return Err((AOC_BAD_ARGUMENT, format!("bad part: {}, expected 1 or 2", part))),
+	};
+	let solution = days::find(day, None).map_err(|e| (AOC_NOT_FOUND, format!("{:#}", e)))?;
+	if !solution.parts.contains(&part) {
+		return Err((AOC_NOT_FOUND, format!("day {} has no part {}", day, part)));
+	}
+	let input = str::from_utf8(input).map_err(|e| (AOC_BAD_INPUT, format!("input isn't utf-8: {}", e)))?;
+
+	solution.run(input, part).map_err(|e| (AOC_FAILED, format!("{:#}", e)))
+}
+
+// as much of the text as fits with a nul after it. a cut short answer is
+// wrong, so that's a different status, but a cut short message is still
+// worth something.
+fn write(out: &mut [u8], status: i32, text: &str) -> i32 {
+	let Some(room) = out.len().checked_sub(1) else {
+		return if status == AOC_OK { AOC_BUFFER_TOO_SMALL } else { status };
+	};
+	// not in the middle of a character, so the message is still utf-8
+	let mut n = usize::min(room, text.len());
+	while !text.is_char_boundary(n) {
+		n -= 1;
+	}
+	out[..n].copy_from_slice(&text.as_bytes()[..n]);
+	out[n] = 0;
+
+	match status == AOC_OK && n < text.len() {
+		true => AOC_BUFFER_TOO_SMALL,
+		false => status,
+	}
+}
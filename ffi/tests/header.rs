@@ -0,0 +1,24 @@
+use std::fs;
+use std::path::Path;
+
+// regenerates include/aoc.h, and fails if that changed it
+#[test]
+fn header() {
+	let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+	let config = cbindgen::Config::from_file(dir.join("cbindgen.toml")).unwrap();
+	let mut header = Vec::new();
+	cbindgen::Builder::new()
+		.with_src(dir.join("src/lib.rs"))
+		.with_config(config)
+		.generate()
+		.unwrap()
+		.write(&mut header);
+	let header = String::from_utf8(header).unwrap();
+
+	let path = dir.join("include/aoc.h");
+	let old = fs::read_to_string(&path).unwrap_or_default();
+	if header != old {
+		fs::write(&path, &header).unwrap();
+		panic!("{} was out of date, check in the new one", path.display());
+	}
+}
@@ -0,0 +1,52 @@
+use std::ffi::CStr;
+use std::ptr;
+
+use aoc::*;
+
+fn solve(day: u32, part: u32, input: &str, out_len: usize) -> (i32, String) {
+	let mut out = vec![0x55u8; out_len];
+	let status = unsafe {
+		aoc_solve(day, part, input.as_ptr(), input.len(), out.as_mut_ptr().cast(), out.len())
+	};
+	let text = match out_len {
+		0 => String::new(),
+		_ => CStr::from_bytes_until_nul(&out).unwrap().to_str().unwrap().to_string(),
+	};
+	(status, text)
+}
+
+#[test]
+fn answers() {
+	assert_eq!(solve(16, 2, "9C0141080250320F1802104A08\n", 64), (AOC_OK, "1".to_string()));
+	assert_eq!(solve(17, 1, "target area: x=20..30, y=-10..-5", 64), (AOC_OK, "45".to_string()));
+	assert_eq!(solve(17, 2, "target area: x=20..30, y=-10..-5", 3), (AOC_BUFFER_TOO_SMALL, "11".to_string()));
+	assert_eq!(solve(17, 2, "target area: x=20..30, y=-10..-5", 0).0, AOC_BUFFER_TOO_SMALL);
+}
+
+#[test]
+fn errors() {
+	let (status, message) = solve(3, 1, "0101\n01x1\n", 256);
+	assert_eq!(status, AOC_FAILED);
+	assert!(message.starts_with("line 2, column 3: "), "{}", message);
+
+	// messages are cut short rather than not there at all
+	assert_eq!(solve(3, 1, "0101\n01x1\n", 5), (AOC_FAILED, "line".to_string()));
+	// but not in the middle of a character
+	let (status, message) = solve(3, 1, "0101\n01é1\n", 50);
+	assert_eq!(status, AOC_FAILED);
+	assert!(message.ends_with("\n2 | 01"), "{:?}", message);
+
+	assert_eq!(solve(16, 3, "", 64).0, AOC_BAD_ARGUMENT);
+	assert_eq!(solve(6, 1, "", 64).0, AOC_NOT_FOUND);
+	assert_eq!(solve(25, 2, "", 64).0, AOC_NOT_FOUND);
+
+	let input = [0xff, b'\n'];
+	let mut out = [0u8; 64];
+	let status = unsafe { aoc_solve(1, 1, input.as_ptr(), input.len(), out.as_mut_ptr().cast(), 64) };
+	assert_eq!(status, AOC_BAD_INPUT);
+
+	let status = unsafe { aoc_solve(1, 1, ptr::null(), 10, out.as_mut_ptr().cast(), 64) };
+	assert_eq!(status, AOC_BAD_ARGUMENT);
+	let status = unsafe { aoc_solve(1, 1, input.as_ptr(), input.len(), ptr::null_mut(), 64) };
+	assert_eq!(status, AOC_BAD_ARGUMENT);
+}
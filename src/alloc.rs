@@ -0,0 +1,113 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+use serde::Serialize;
+
+// the system allocator, counting what goes through it. it's only installed
+// with the alloc-stats feature, since every allocation pays for the atomics.
+pub struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+fn allocated(size: usize) {
+	ALLOCATIONS.fetch_add(1, Relaxed);
+	BYTES.fetch_add(size, Relaxed);
+	let live = LIVE.fetch_add(size, Relaxed) + size;
+	PEAK.fetch_max(live, Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let p = System.alloc(layout);
+		if !p.is_null() {
+			allocated(layout.size());
+		}
+		p
+	}
+
+	unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+		let p = System.alloc_zeroed(layout);
+		if !p.is_null() {
+			allocated(layout.size());
+		}
+		p
+	}
+
+	unsafe fn dealloc(&self, p: *mut u8, layout: Layout) {
+		System.dealloc(p, layout);
+		LIVE.fetch_sub(layout.size(), Relaxed);
+	}
+
+	// counts as freeing the old block and allocating the new one
+	unsafe fn realloc(&self, p: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		let new = System.realloc(p, layout, new_size);
+		if !new.is_null() {
+			LIVE.fetch_sub(layout.size(), Relaxed);
+			allocated(new_size);
+		}
+		new
+	}
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Stats {
+	pub allocations: usize,
+	pub bytes: usize,
+	// the most that was allocated at once, on top of what already was
+	pub peak_bytes: usize,
+}
+
+impl fmt::Display for Stats {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} allocations, {} allocated, {} peak",
+			self.allocations, Bytes(self.bytes), Bytes(self.peak_bytes))
+	}
+}
+
+struct Bytes(usize);
+
+impl fmt::Display for Bytes {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+		if self.0 < 1024 {
+			return write!(f, "{} B", self.0);
+		}
+		let mut n = self.0 as f64 / 1024.0;
+		let mut unit = 0;
+		while n >= 1024.0 && unit + 1 < UNITS.len() {
+			n /= 1024.0;
+			unit += 1;
+		}
+		write!(f, "{:.1} {}", n, UNITS[unit])
+	}
+}
+
+pub fn enabled() -> bool {
+	cfg!(feature = "alloc-stats")
+}
+
+// what `f` allocates, or nothing if the counting allocator isn't installed.
+// anything allocating on other threads at the same time gets counted too.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Stats>) {
+	if !enabled() {
+		return (f(), None);
+	}
+
+	let allocations = ALLOCATIONS.load(Relaxed);
+	let bytes = BYTES.load(Relaxed);
+	let live = LIVE.load(Relaxed);
+	PEAK.store(live, Relaxed);
+
+	let result = f();
+
+	let stats = Stats {
+		allocations: ALLOCATIONS.load(Relaxed) - allocations,
+		bytes: BYTES.load(Relaxed) - bytes,
+		peak_bytes: PEAK.load(Relaxed).saturating_sub(live),
+	};
+	(result, Some(stats))
+}
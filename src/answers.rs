@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Deserializer};
+
+use crate::input;
+use crate::solution::Part;
+
+// answers.toml maps input files to the answers accepted for them:
+//
+//     ["inputs/day01.txt"]
+//     day = 1
+//     part1 = "1301"
+//     part2 = "1346"
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Entry {
+	pub day: u32,
+	#[serde(default, deserialize_with = "answer")]
+	pub part1: Option<String>,
+	#[serde(default, deserialize_with = "answer")]
+	pub part2: Option<String>,
+}
+
+impl Entry {
+	pub fn get(&self, part: Part) -> Option<&str> {
+		match part {
+			Part::One => self.part1.as_deref(),
+			Part::Two => self.part2.as_deref(),
+		}
+	}
+}
+
+// answers are compared as text, but writing numbers without quotes is fine
+fn answer<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+	#[derive(Deserialize)]
+	#[serde(untagged)]
+	enum Answer {
+		Number(i64),
+		Text(String),
+	}
+
+	Ok(Some(match Answer::deserialize(d)? {
+		Answer::Number(n) => n.to_string(),
+		Answer::Text(s) => s,
+	}))
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Ledger {
+	entries: BTreeMap<PathBuf, Entry>,
+}
+
+impl Ledger {
+	pub fn load(path: &Path) -> Result<Ledger> {
+		let text = input::read_file(path)?;
+		text.parse().wrap_err_with(|| format!("bad answers file {}", path.display()))
+	}
+
+	pub fn get(&self, input: &Path) -> Option<&Entry> {
+		self.entries.get(input)
+	}
+
+	pub fn expected(&self, input: &Path, part: Part) -> Option<&str> {
+		self.get(input)?.get(part)
+	}
+
+	pub fn inputs(&self, day: u32) -> impl Iterator<Item=&Path> {
+		self.entries.iter()
+			.filter(move |(_, e)| e.day == day)
+			.map(|(path, _)| &**path)
+	}
+}
+
+impl std::str::FromStr for Ledger {
+	type Err = eyre::Report;
+
+	fn from_str(s: &str) -> Result<Ledger> {
+		let entries: BTreeMap<PathBuf, Entry> = toml::from_str(s)?;
+		Ok(Ledger { entries })
+	}
+}
@@ -0,0 +1,72 @@
+use std::time::{Duration, Instant};
+
+use eyre::{Result, ensure};
+
+use crate::alloc;
+use crate::days::Day;
+use crate::solution::Part;
+
+#[derive(Debug, Clone, Default)]
+pub struct Timings {
+	samples: Vec<Duration>,
+	// how many allocations the last run made, with the alloc-stats feature
+	pub allocations: Option<usize>,
+}
+
+impl Timings {
+	pub fn add(&mut self, sample: Duration) {
+		let i = self.samples.partition_point(|&s| s < sample);
+		self.samples.insert(i, sample);
+	}
+
+	pub fn min(&self) -> Duration {
+		self.samples.first().copied().unwrap_or_default()
+	}
+
+	pub fn median(&self) -> Duration {
+		let n = self.samples.len();
+		match n {
+			0 => Duration::ZERO,
+			_ if n % 2 == 1 => self.samples[n / 2],
+			_ => (self.samples[n / 2 - 1] + self.samples[n / 2]) / 2,
+		}
+	}
+
+	pub fn max(&self) -> Duration {
+		self.samples.last().copied().unwrap_or_default()
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+	pub parse: Timings,
+	pub parts: Vec<(Part, Timings)>,
+}
+
+// parses and solves every part `runs` times, timing each stage separately
+pub fn bench(solution: &Day, input: &str, runs: usize) -> Result<Report> {
+	ensure!(runs > 0, "need at least one run");
+
+	let mut parse = Timings::default();
+	let mut parts: Vec<_> = solution.parts.iter()
+		.map(|&part| (part, Timings::default()))
+		.collect();
+
+	for _ in 0..runs {
+		let start = Instant::now();
+		let (parsed, memory) = alloc::measure(|| (solution.parse)(input));
+		parse.add(start.elapsed());
+		parse.allocations = memory.map(|m| m.allocations);
+		let parsed = parsed?;
+
+		for (part, timings) in &mut parts {
+			let start = Instant::now();
+			let (result, memory) = alloc::measure(|| (solution.solve)(&parsed, *part));
+			timings.add(start.elapsed());
+			timings.allocations = memory.map(|m| m.allocations);
+			result?;
+		}
+	}
+
+	Ok(Report { parse, parts })
+}
@@ -0,0 +1,101 @@
+use std::time::{Duration, Instant};
+
+use eyre::{Result, eyre};
+
+use advent_of_code_2021::cancel;
+use advent_of_code_2021::days::{DAYS, Day};
+use advent_of_code_2021::input::{self, Source};
+use advent_of_code_2021::outcome::{Outcome, panic_message};
+use advent_of_code_2021::pool;
+use advent_of_code_2021::solution::Part;
+
+use crate::table::Table;
+
+// solves every part of every day with its default implementation on a pool
+// of `jobs` threads, then prints all results at once
+pub fn run_all(part: Option<Part>, example: bool, jobs: usize, timeout: Option<Duration>,
+		json: bool) -> Result<bool> {
+	let mut defaults: Vec<&Day> = Vec::new();
+	for solution in DAYS {
+		if defaults.last().is_none_or(|d| d.day != solution.day) {
+			defaults.push(solution);
+		}
+	}
+
+	// one task per day, so its input is only parsed once for all its parts
+	let tasks: Vec<(&Day, Vec<Part>)> = defaults.iter()
+		.map(|&solution| (solution, solution.parts.iter().copied()
+			.filter(|&p| part.is_none_or(|part| part == p))
+			.collect::<Vec<_>>()))
+		.filter(|(_, parts)| !parts.is_empty())
+		.collect();
+
+	let start = Instant::now();
+	let results = pool::map(&tasks, jobs, |(solution, parts)| {
+		let path = input::default_path(solution.day, example);
+		if !path.exists() {
+			return None;
+		}
+
+		let source = Source::File(path);
+		let name = source.to_string();
+		Some(match source.read().and_then(|input| (solution.parse)(&input)) {
+			Ok(parsed) => parts.iter()
+				.map(|&part| cancel::with_timeout(timeout,
+					|| Outcome::solve(solution, &parsed, &name, part)))
+				.collect(),
+			Err(e) => failed(solution, &name, parts, &e),
+		})
+	});
+	// a day that panicked had an input to panic on
+	let results: Vec<Option<Vec<Outcome>>> = tasks.iter().zip(results)
+		.map(|((solution, parts), result)| result.unwrap_or_else(|payload| {
+			let source = Source::File(input::default_path(solution.day, example)).to_string();
+			let e = eyre!("panicked: {}", panic_message(&*payload));
+			Some(failed(solution, &source, parts, &e))
+		}))
+		.collect();
+	let elapsed = start.elapsed();
+
+	let outcomes = || results.iter().flatten().flatten();
+	let all_ok = outcomes().all(Outcome::is_ok);
+
+	if json {
+		for outcome in outcomes() {
+			println!("{}", serde_json::to_string(outcome)?);
+		}
+		return Ok(all_ok);
+	}
+
+	let mut table = Table::new(&["day", "part", "answer", "time", "status"]);
+	let rows = tasks.iter().zip(&results).flat_map(|((solution, parts), outcomes)|
+		parts.iter().enumerate().map(move |(i, &part)|
+			(solution, part, outcomes.as_ref().map(|o| &o[i]))));
+	for (solution, part, outcome) in rows {
+		let (answer, time, status) = match outcome {
+			None => (String::new(), String::new(), "no input".to_string()),
+			Some(o) => (
+				o.answer.clone().unwrap_or_default(),
+				format!("{:.2?}", o.duration),
+				match &o.error {
+					None => "ok".to_string(),
+					Some(e) => format!("ERROR: {}", e.lines().next().unwrap_or_default()),
+				},
+			),
+		};
+		table.row(vec![solution.day.to_string(), part.to_string(), answer, time, status]);
+	}
+	print!("{}", table);
+
+	let tried = outcomes().count();
+	let solved = outcomes().filter(|o| o.is_ok()).count();
+	let total: usize = tasks.iter().map(|(_, parts)| parts.len()).sum();
+	println!("\n{} parts solved and {} failed in {:.2?} on {} threads, {} skipped for lack of input",
+		solved, tried - solved, elapsed, jobs, total - tried);
+
+	Ok(all_ok)
+}
+
+fn failed(solution: &Day, source: &str, parts: &[Part], error: &eyre::Report) -> Vec<Outcome> {
+	parts.iter().map(|&part| Outcome::failed(solution, source, part, error)).collect()
+}
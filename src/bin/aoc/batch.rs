@@ -0,0 +1,101 @@
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use eyre::{Result, WrapErr, bail, eyre};
+
+use advent_of_code_2021::cancel;
+use advent_of_code_2021::days::Day;
+use advent_of_code_2021::input::read_file;
+use advent_of_code_2021::outcome::{Outcome, panic_message};
+use advent_of_code_2021::solution::{Parsed, Part};
+
+use crate::table::Table;
+
+// every file in the directory, in order, skipping hidden ones and
+// subdirectories
+fn inputs(dir: &Path) -> Result<Vec<PathBuf>> {
+	let mut paths = Vec::new();
+	for entry in fs::read_dir(dir).wrap_err_with(|| format!("couldn't list {}", dir.display()))? {
+		let entry = entry.wrap_err_with(|| format!("couldn't list {}", dir.display()))?;
+		let hidden = entry.file_name().to_string_lossy().starts_with('.');
+		if !hidden && entry.path().is_file() {
+			paths.push(entry.path());
+		}
+	}
+	paths.sort();
+	Ok(paths)
+}
+
+// a solution that only works for one input might as well panic on the others,
+// which shouldn't stop the rest from being solved
+fn parse(solution: &Day, input: &str) -> Result<Parsed> {
+	panic::catch_unwind(AssertUnwindSafe(|| (solution.parse)(input)))
+		.unwrap_or_else(|payload| Err(eyre!("panicked: {}", panic_message(&*payload))))
+}
+
+fn solve(solution: &Day, parsed: &Parsed, name: &str, part: Part, timeout: Option<Duration>)
+		-> Outcome {
+	let result = panic::catch_unwind(AssertUnwindSafe(
+		|| cancel::with_timeout(timeout, || Outcome::solve(solution, parsed, name, part))));
+	result.unwrap_or_else(|payload| {
+		Outcome::failed(solution, name, part, &eyre!("panicked: {}", panic_message(&*payload)))
+	})
+}
+
+// solves the parts on every input in a directory, one after the other, then
+// prints all results at once
+pub fn run_batch(solution: &Day, parts: &[Part], dir: &Path, timeout: Option<Duration>,
+		json: bool) -> Result<bool> {
+	let paths = inputs(dir)?;
+	if paths.is_empty() {
+		bail!("no inputs in {}", dir.display());
+	}
+
+	let mut outcomes = Vec::new();
+	for path in &paths {
+		let name = path.display().to_string();
+		match read_file(path).and_then(|input| parse(solution, &input)) {
+			Ok(parsed) => outcomes.extend(parts.iter()
+				.map(|&part| solve(solution, &parsed, &name, part, timeout))),
+			Err(e) => outcomes.extend(parts.iter()
+				.map(|&part| Outcome::failed(solution, &name, part, &e))),
+		}
+	}
+
+	let all_ok = outcomes.iter().all(Outcome::is_ok);
+
+	if json {
+		for outcome in &outcomes {
+			println!("{}", serde_json::to_string(outcome)?);
+		}
+		return Ok(all_ok);
+	}
+
+	let mut table = Table::new(&["input", "part", "answer", "time", "status"]);
+	for o in &outcomes {
+		let file = Path::new(&o.input).file_name().map_or(o.input.clone(),
+			|name| name.to_string_lossy().into_owned());
+		table.row(vec![
+			file,
+			o.part.to_string(),
+			o.answer.clone().unwrap_or_default(),
+			format!("{:.2?}", o.duration),
+			match &o.error {
+				None => "ok".to_string(),
+				// diagnostics quote the input over a few more lines
+				Some(e) => format!("ERROR: {}", e.lines().next().unwrap_or_default()),
+			},
+		]);
+	}
+	print!("{}", table);
+
+	let solved = outcomes.chunks(parts.len())
+		.filter(|file| file.iter().all(Outcome::is_ok))
+		.count();
+	println!("\n{} of {} inputs in {} solved by day {} ({})",
+		solved, paths.len(), dir.display(), solution.day, solution.name);
+
+	Ok(all_ok)
+}
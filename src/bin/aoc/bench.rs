@@ -0,0 +1,116 @@
+use std::path::PathBuf;
+
+use eyre::Result;
+
+use advent_of_code_2021::bench::{self, Timings};
+use advent_of_code_2021::days::Day;
+use advent_of_code_2021::history::{self, Record};
+use advent_of_code_2021::input::Source;
+
+use crate::table::Table;
+
+// what to do with the benchmark history file
+pub struct History {
+	pub path: PathBuf,
+	pub save: bool,
+	pub compare: bool,
+	// how much slower than the baseline counts as a regression, as a fraction
+	pub threshold: f64,
+}
+
+// benchmarks each solution on its input and prints min/median/max per stage.
+// when benchmarking everything, days without an input are skipped.
+pub fn bench(solutions: &[&Day], path: Option<&str>, example: bool, runs: usize,
+		history: &History) -> Result<bool> {
+	let baselines = match history.compare {
+		true => history::load(&history.path)?,
+		false => Vec::new(),
+	};
+	let (time, commit) = (history::now(), history::commit());
+
+	let mut header = vec!["day", "impl", "stage", "min", "median", "max"];
+	if history.compare {
+		header.extend(["baseline", "change", "status"]);
+	}
+	let mut table = Table::new(&header);
+	let mut records = Vec::new();
+	let mut regressions = 0;
+	let mut all_ok = true;
+
+	for solution in solutions {
+		let source = Source::resolve(solution.day, path, example);
+		if solutions.len() > 1 && matches!(&source, Source::File(p) if !p.exists()) {
+			continue;
+		}
+
+		let report = source.read().and_then(|input|
+			Ok((history::input_hash(&input), bench::bench(solution, &input, runs)?)));
+		let (input, report) = match report {
+			Ok(report) => report,
+			Err(e) => {
+				eprintln!("aoc: day {} ({}): {:#}", solution.day, solution.name, e);
+				all_ok = false;
+				continue;
+			}
+		};
+
+		let stages = std::iter::once(("parse".to_string(), &report.parse))
+			.chain(report.parts.iter().map(|(part, t)| (format!("part {}", part), t)));
+		for (stage, timings) in stages {
+			let record = Record {
+				time,
+				commit: commit.clone(),
+				day: solution.day,
+				name: solution.name.to_string(),
+				input: input.clone(),
+				stage: stage.clone(),
+				median: timings.median(),
+				allocations: timings.allocations,
+			};
+
+			let mut row = row(solution, stage, timings);
+			if history.compare {
+				match history::baseline(&baselines, &record) {
+					Some(baseline) => {
+						let change = history::change(baseline, &record);
+						let regressed = change > history.threshold;
+						regressions += regressed as usize;
+						row.extend([
+							format!("{:.2?} ({})", baseline.median, baseline.commit),
+							format!("{:+.1}%", change * 100.0),
+							if regressed { "REGRESSED" } else { "ok" }.to_string(),
+						]);
+					}
+					None => row.extend([String::new(), String::new(), "new".to_string()]),
+				}
+			}
+			table.row(row);
+			records.push(record);
+		}
+	}
+
+	print!("{}", table);
+
+	if history.compare && regressions > 0 {
+		println!("\n{} stages regressed by more than {:.1}% since the last saved results in {}",
+			regressions, history.threshold * 100.0, history.path.display());
+		all_ok = false;
+	}
+	if history.save {
+		history::append(&history.path, &records)?;
+		println!("\nsaved {} results for {} to {}", records.len(), commit, history.path.display());
+	}
+
+	Ok(all_ok)
+}
+
+fn row(solution: &Day, stage: String, timings: &Timings) -> Vec<String> {
+	vec![
+		solution.day.to_string(),
+		solution.name.to_string(),
+		stage,
+		format!("{:.2?}", timings.min()),
+		format!("{:.2?}", timings.median()),
+		format!("{:.2?}", timings.max()),
+	]
+}
@@ -0,0 +1,609 @@
+use std::env;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use eyre::{Result, eyre, bail};
+
+use advent_of_code_2021::answers::Ledger;
+use advent_of_code_2021::cancel;
+use advent_of_code_2021::checkpoint;
+use advent_of_code_2021::days::{self, Day};
+use advent_of_code_2021::generate;
+use advent_of_code_2021::image::{self, Exporter, Images};
+use advent_of_code_2021::outcome::Outcome;
+use advent_of_code_2021::pool;
+use advent_of_code_2021::progress;
+use advent_of_code_2021::render::{self, Renderer, Screen};
+use advent_of_code_2021::serve;
+use advent_of_code_2021::trace;
+use advent_of_code_2021::solution::Part;
+use advent_of_code_2021::input::Source;
+
+mod all;
+mod batch;
+mod bench;
+mod table;
+mod verify;
+
+const USAGE: &str = "\
+usage: aoc [-v | -vv] [--trace <target>,...] <command> ...
+
+       aoc run <day> [--part 1|2] [--impl <name>] [--input <path> | --example]
+               [--timeout <secs>] [--format text|json] [--progress]
+               [--checkpoint <path> [--resume]]
+       aoc run <day> --inputs-dir <dir> [--part 1|2] [--impl <name>]
+               [--timeout <secs>] [--format text|json]
+       aoc run <day> --render [--part 1|2] [--input <path> | --example]
+               [--fps <n>] [--viewport <w>x<h>[+<x>+<y>]]
+       aoc run <day> --image <dir> [--part 1|2] [--input <path> | --example]
+       aoc run --all [--part 1|2] [--example] [--jobs <n>] [--timeout <secs>]
+               [--format text|json]
+       aoc verify [<day>] [--answers <path>] [--timeout <secs>]
+       aoc bench [<day>] [--impl <name>] [--runs <n>] [--input <path> | --example]
+               [--save] [--compare] [--threshold <percent>] [--history <path>]
+       aoc generate <day> [--seed <n>] [--size <n>]
+       aoc serve --stdio [--timeout <secs>]
+
+Reads the puzzle input and prints the answer for the requested part, or one
+line per part if no part is given.
+
+The input is read from inputs/dayNN.txt, or inputs/dayNN.example.txt with
+--example, unless --input names another file. --input - reads from stdin.
+With --format json, every part is printed as one json object per line with
+its answer, duration and error, if any.
+
+run --inputs-dir solves every file in a directory, like everyone's personal
+inputs, and prints a table of the answers and timings for each. a file that
+fails, even by panicking, doesn't stop the others.
+
+--timeout gives every part that many seconds, after which it fails with how
+far it got. verify reports those as TIMEOUT.
+
+--progress prints how far day 17's search has got, how fast it's going and
+how long it should take, to stderr every second. --checkpoint saves where the
+search is up to in a file every few seconds, and when it times out or fails;
+with --resume it starts from there, if the file is from the same search.
+
+run --render draws the puzzle instead of solving it, for days 5, 17 and 25:
+the overlapping lines, the highest throw, or every generation of the sea
+cucumbers. on a terminal it animates at up to --fps frames per second (10 by
+default, 0 for no limit), in colour unless NO_COLOR is set, and crops to the
+size of the screen, or to COLUMNS and LINES if stty can't tell; --viewport
+picks the part that's shown instead. otherwise every frame is printed in full,
+one after the other.
+
+run --image saves pictures as pgm or ppm files in a directory, one pixel per
+cell, and prints their paths: the overlap heatmap for day 5, the risk map with
+the safest path for day 15, the highest throw for day 17, and one image per
+generation, numbered in order, for day 25.
+
+run --all solves every day with its default implementation, using up to
+--jobs threads (one per cpu by default), and prints a summary table. Days
+without an input are skipped.
+
+verify runs every implementation on each input listed in answers.toml, and
+on the default inputs if present, and compares against the recorded answers.
+
+bench parses and solves each part --runs times (10 by default) and prints
+the fastest, median and slowest time for every stage. Without a day it
+benchmarks every implementation that has an input; without --impl, every
+implementation of the given day.
+
+bench --save appends the commit, a hash of the input, median time and, with
+alloc-stats, number of allocations for every stage to a csv file,
+bench-history.csv unless --history names another. --compare shows how much
+each stage changed since it was last saved there for the same input, and
+fails if any got more than --threshold percent slower (10 by default). with
+both, it compares before saving.
+
+generate prints a random input for a day, the same one for the same seed (0
+by default). what --size counts depends on the day: lines, squares, packets,
+scanners or the side of a map. the default is about the size of a real input.
+
+Built with --features alloc-stats, run also prints how many allocations each
+part made, how many bytes they added up to, and the most that was allocated at
+once, to stderr or as part of the json. with --all, parts running at the same
+time on other threads get counted too, unless it's --jobs 1.
+
+serve --stdio keeps running and answers requests from stdin, one json object
+per line like {\"id\": 1, \"day\": 16, \"part\": 2, \"input\": \"...\"}, with \"impl\"
+and \"timeout\" in seconds optional. every request gets one json line on stdout
+with the same id, the answer and duration_ms, or an error with its kind
+(bad_request, not_found, parse, solve, timeout or panic), message, and the
+line and column for parse errors.
+
+-v prints diagnostics from the solutions to stderr, -vv prints a lot more.
+--trace limits them to some days or implementations, like 16 or d16::bitvec,
+and implies -v.
+
+exit status: 0 on success, 1 if solving anything failed or verify found a wrong
+answer, 2 on bad arguments or an unknown day, part or implementation";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+	Text,
+	Json,
+}
+
+impl std::str::FromStr for Format {
+	type Err = eyre::Report;
+
+	fn from_str(s: &str) -> Result<Format> {
+		match s {
+			"text" => Ok(Format::Text),
+			"json" => Ok(Format::Json),
+			_ => bail!("bad format: {:?}, expected text or json", s),
+		}
+	}
+}
+
+enum Command {
+	Help,
+	Run {
+		solution: &'static Day,
+		parts: Vec<Part>,
+		source: Source,
+		timeout: Option<Duration>,
+		format: Format,
+		show_progress: bool,
+		checkpoint: Option<PathBuf>,
+		resume: bool,
+	},
+	Batch {
+		solution: &'static Day,
+		parts: Vec<Part>,
+		dir: PathBuf,
+		timeout: Option<Duration>,
+		format: Format,
+	},
+	Render {
+		renderer: &'static Renderer,
+		part: Part,
+		source: Source,
+		options: render::Options,
+	},
+	Export {
+		exporter: &'static Exporter,
+		part: Part,
+		source: Source,
+		dir: PathBuf,
+	},
+	RunAll {
+		part: Option<Part>,
+		example: bool,
+		jobs: usize,
+		timeout: Option<Duration>,
+		format: Format,
+	},
+	Verify {
+		day: Option<u32>,
+		answers: PathBuf,
+		timeout: Option<Duration>,
+	},
+	Bench {
+		solutions: Vec<&'static Day>,
+		path: Option<String>,
+		example: bool,
+		runs: usize,
+		history: bench::History,
+	},
+	Serve {
+		timeout: Option<Duration>,
+	},
+	Generate {
+		day: u32,
+		seed: u64,
+		size: Option<usize>,
+	},
+}
+
+fn parse_day(arg: &str) -> Result<u32> {
+	arg.parse().map_err(|_| eyre!("bad day: {:?}", arg))
+}
+
+fn parse_timeout(arg: &str) -> Result<Duration> {
+	arg.parse().ok()
+		.and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+		.filter(|t| !t.is_zero())
+		.ok_or_else(|| eyre!("bad timeout: {:?}, expected a number of seconds", arg))
+}
+
+// pulls the tracing options out of the arguments, wherever they are, and
+// returns the remaining ones
+fn parse_trace_args(mut args: impl Iterator<Item=String>)
+		-> Result<(u8, Vec<String>, Vec<String>)> {
+	let mut verbosity = 0;
+	let mut targets = Vec::new();
+	let mut rest = Vec::new();
+
+	while let Some(arg) = args.next() {
+		match &*arg {
+			"-v" => verbosity += 1,
+			"-vv" => verbosity += 2,
+			"--trace" => {
+				let value = args.next().ok_or_else(|| eyre!("--trace needs a value"))?;
+				for target in value.split(',') {
+					match target.starts_with(|c: char| c.is_ascii_digit()) {
+						true => targets.push(format!("d{}", target)),
+						false => targets.push(target.to_string()),
+					}
+				}
+			}
+			_ => rest.push(arg),
+		}
+	}
+
+	if !targets.is_empty() {
+		verbosity = u8::max(verbosity, trace::DEBUG);
+	}
+
+	Ok((verbosity, targets, rest))
+}
+
+fn parse_args(mut args: impl Iterator<Item=String>) -> Result<Command> {
+	let command = match args.next() {
+		Some(command) => command,
+		None => bail!("missing command"),
+	};
+
+	match &*command {
+		"help" | "-h" | "--help" => Ok(Command::Help),
+		"run" => {
+			let mut day = None;
+			let mut part = None;
+			let mut name = None;
+			let mut path = None;
+			let mut example = false;
+			let mut format = Format::Text;
+			let mut all = false;
+			let mut jobs = None;
+			let mut render = false;
+			let mut fps = None;
+			let mut viewport = None;
+			let mut image = None;
+			let mut timeout = None;
+			let mut show_progress = false;
+			let mut checkpoint = None;
+			let mut resume = false;
+			let mut inputs_dir = None;
+
+			while let Some(arg) = args.next() {
+				let mut value = |flag: &str| args.next()
+					.ok_or_else(|| eyre!("{} needs a value", flag));
+				match &*arg {
+					"--all" => all = true,
+					"--jobs" => jobs = Some(value("--jobs")?.parse()
+						.map_err(|_| eyre!("bad number of jobs"))?),
+					"--part" => part = Some(value("--part")?.parse()?),
+					"--format" => format = value("--format")?.parse()?,
+					"--impl" => name = Some(value("--impl")?),
+					"--input" => path = Some(value("--input")?),
+					"--example" => example = true,
+					"--render" => render = true,
+					"--fps" => fps = Some(value("--fps")?.parse::<f64>().ok()
+						.filter(|fps| *fps >= 0.0)
+						.ok_or_else(|| eyre!("bad frame rate"))?),
+					"--viewport" => viewport = Some(value("--viewport")?.parse()?),
+					"--image" => image = Some(PathBuf::from(value("--image")?)),
+					"--timeout" => timeout = Some(parse_timeout(&value("--timeout")?)?),
+					"--progress" => show_progress = true,
+					"--checkpoint" => checkpoint = Some(PathBuf::from(value("--checkpoint")?)),
+					"--resume" => resume = true,
+					"--inputs-dir" => inputs_dir = Some(PathBuf::from(value("--inputs-dir")?)),
+					_ if arg.starts_with('-') => bail!("unknown option: {}", arg),
+					_ if day.is_none() => day = Some(parse_day(&arg)?),
+					_ => bail!("unexpected argument: {}", arg),
+				}
+			}
+
+			if resume && checkpoint.is_none() {
+				bail!("--resume needs a --checkpoint to resume from");
+			}
+			if (all || render || image.is_some()) && (show_progress || checkpoint.is_some()) {
+				bail!("--progress and --checkpoint only work for a single day");
+			}
+			if inputs_dir.is_some() && (all || render || image.is_some() || example
+					|| path.is_some() || show_progress || checkpoint.is_some()) {
+				bail!("--inputs-dir can't be combined with --all, --render, --image, --example, \
+					--input, --progress or --checkpoint");
+			}
+			if !render && (fps.is_some() || viewport.is_some()) {
+				bail!("--fps and --viewport only make sense with --render");
+			}
+			if render || image.is_some() {
+				if render && image.is_some() {
+					bail!("--render and --image can't be used together");
+				}
+				if all || jobs.is_some() || name.is_some() || timeout.is_some()
+						|| format != Format::Text {
+					bail!("--render and --image can't be combined with --all, --impl, --timeout or --format");
+				}
+				if example && path.is_some() {
+					bail!("--example and --input can't be used together");
+				}
+				let day = day.ok_or_else(|| eyre!("missing day"))?;
+				let solution = days::find(day, None)?;
+				let part = match part {
+					Some(part) if !solution.parts.contains(&part) =>
+						bail!("day {} has no part {}", day, part),
+					Some(part) => part,
+					None => *solution.parts.last().unwrap_or(&Part::One),
+				};
+				let source = Source::resolve(day, path.as_deref(), example);
+
+				if let Some(dir) = image {
+					let exporter = image::find(day)?;
+					return Ok(Command::Export { exporter, part, source, dir });
+				}
+
+				let renderer = render::find(day)?;
+				let mut options = render::Options::for_stdout();
+				options.fps = fps.unwrap_or(options.fps);
+				options.viewport = viewport.unwrap_or(options.viewport);
+
+				return Ok(Command::Render { renderer, part, source, options });
+			}
+
+			if all {
+				if day.is_some() || name.is_some() || path.is_some() {
+					bail!("--all can't be combined with a day, --impl or --input");
+				}
+				let jobs = jobs.unwrap_or_else(pool::default_workers);
+				if jobs == 0 {
+					bail!("need at least one job");
+				}
+				return Ok(Command::RunAll { part, example, jobs, timeout, format });
+			}
+			if jobs.is_some() {
+				bail!("--jobs only makes sense with --all");
+			}
+
+			let day = day.ok_or_else(|| eyre!("missing day"))?;
+			let solution = days::find(day, name.as_deref())?;
+			let parts = match part {
+				Some(part) if !solution.parts.contains(&part) =>
+					bail!("day {} has no part {}", day, part),
+				Some(part) => vec![part],
+				None => solution.parts.to_vec(),
+			};
+
+			if let Some(dir) = inputs_dir {
+				return Ok(Command::Batch { solution, parts, dir, timeout, format });
+			}
+
+			if example && path.is_some() {
+				bail!("--example and --input can't be used together");
+			}
+			let source = Source::resolve(day, path.as_deref(), example);
+
+			Ok(Command::Run {
+				solution, parts, source, timeout, format, show_progress, checkpoint, resume,
+			})
+		}
+		"verify" => {
+			let mut day = None;
+			let mut answers = PathBuf::from("answers.toml");
+			let mut timeout = None;
+
+			while let Some(arg) = args.next() {
+				let mut value = |flag: &str| args.next()
+					.ok_or_else(|| eyre!("{} needs a value", flag));
+				match &*arg {
+					"--answers" => answers = value("--answers")?.into(),
+					"--timeout" => timeout = Some(parse_timeout(&value("--timeout")?)?),
+					_ if arg.starts_with('-') => bail!("unknown option: {}", arg),
+					_ if day.is_none() => day = Some(parse_day(&arg)?),
+					_ => bail!("unexpected argument: {}", arg),
+				}
+			}
+
+			if let Some(day) = day {
+				days::find(day, None)?;
+			}
+
+			Ok(Command::Verify { day, answers, timeout })
+		}
+		"bench" => {
+			let mut day = None;
+			let mut name = None;
+			let mut path = None;
+			let mut example = false;
+			let mut runs = 10;
+			let mut history = bench::History {
+				path: PathBuf::from("bench-history.csv"),
+				save: false,
+				compare: false,
+				threshold: 0.1,
+			};
+
+			while let Some(arg) = args.next() {
+				let mut value = |flag: &str| args.next()
+					.ok_or_else(|| eyre!("{} needs a value", flag));
+				match &*arg {
+					"--impl" => name = Some(value("--impl")?),
+					"--runs" => runs = value("--runs")?.parse()
+						.map_err(|_| eyre!("bad number of runs"))?,
+					"--input" => path = Some(value("--input")?),
+					"--example" => example = true,
+					"--save" => history.save = true,
+					"--compare" => history.compare = true,
+					"--history" => history.path = PathBuf::from(value("--history")?),
+					"--threshold" => history.threshold = value("--threshold")?.parse::<f64>().ok()
+						.filter(|percent| *percent >= 0.0)
+						.ok_or_else(|| eyre!("bad threshold, expected a percentage"))? / 100.0,
+					_ if arg.starts_with('-') => bail!("unknown option: {}", arg),
+					_ if day.is_none() => day = Some(parse_day(&arg)?),
+					_ => bail!("unexpected argument: {}", arg),
+				}
+			}
+
+			if example && path.is_some() {
+				bail!("--example and --input can't be used together");
+			}
+			if runs == 0 {
+				bail!("need at least one run");
+			}
+
+			let solutions = match (day, name) {
+				(Some(day), Some(name)) => vec![days::find(day, Some(&name))?],
+				(Some(day), None) => {
+					days::find(day, None)?;
+					days::DAYS.iter().filter(|d| d.day == day).collect()
+				}
+				(None, Some(_)) => bail!("--impl needs a day"),
+				(None, None) if path.is_some() => bail!("--input needs a day"),
+				(None, None) => days::DAYS.iter().collect(),
+			};
+
+			Ok(Command::Bench { solutions, path, example, runs, history })
+		}
+		"serve" => {
+			let mut stdio = false;
+			let mut timeout = None;
+
+			while let Some(arg) = args.next() {
+				let mut value = |flag: &str| args.next()
+					.ok_or_else(|| eyre!("{} needs a value", flag));
+				match &*arg {
+					"--stdio" => stdio = true,
+					"--timeout" => timeout = Some(parse_timeout(&value("--timeout")?)?),
+					_ if arg.starts_with('-') => bail!("unknown option: {}", arg),
+					_ => bail!("unexpected argument: {}", arg),
+				}
+			}
+
+			// the only way to talk to it so far, but it shouldn't be the
+			// default if there are ever others
+			if !stdio {
+				bail!("serve needs --stdio");
+			}
+
+			Ok(Command::Serve { timeout })
+		}
+		"generate" => {
+			let mut day = None;
+			let mut seed = 0;
+			let mut size = None;
+
+			while let Some(arg) = args.next() {
+				let mut value = |flag: &str| args.next()
+					.ok_or_else(|| eyre!("{} needs a value", flag));
+				match &*arg {
+					"--seed" => seed = value("--seed")?.parse()
+						.map_err(|_| eyre!("bad seed"))?,
+					"--size" => size = Some(value("--size")?.parse()
+						.map_err(|_| eyre!("bad size"))?),
+					_ if arg.starts_with('-') => bail!("unknown option: {}", arg),
+					_ if day.is_none() => day = Some(parse_day(&arg)?),
+					_ => bail!("unexpected argument: {}", arg),
+				}
+			}
+
+			let day = day.ok_or_else(|| eyre!("missing day"))?;
+			generate::find(day)?;
+
+			Ok(Command::Generate { day, seed, size })
+		}
+		_ => bail!("unknown command: {}", command),
+	}
+}
+
+fn run(solution: &Day, parts: &[Part], source: &Source, timeout: Option<Duration>,
+		format: Format) -> Result<bool> {
+	let name = source.to_string();
+	let parsed = source.read().and_then(|input| (solution.parse)(&input));
+	let outcomes: Vec<_> = match parsed {
+		Ok(parsed) => parts.iter()
+			.map(|&part| cancel::with_timeout(timeout,
+				|| Outcome::solve(solution, &parsed, &name, part)))
+			.collect(),
+		Err(e) if format == Format::Json => parts.iter()
+			.map(|&part| Outcome::failed(solution, &name, part, &e))
+			.collect(),
+		Err(e) => return Err(e),
+	};
+
+	for outcome in &outcomes {
+		match (format, &outcome.answer, &outcome.error) {
+			(Format::Json, _, _) => println!("{}", serde_json::to_string(outcome)?),
+			(Format::Text, Some(answer), _) if parts.len() == 1 => println!("{}", answer),
+			(Format::Text, Some(answer), _) => println!("part {}: {}", outcome.part, answer),
+			(Format::Text, None, error) => {
+				eprintln!("aoc: part {}: {}", outcome.part, error.as_deref().unwrap_or_default());
+			}
+		}
+		if let (Format::Text, Some(memory)) = (format, outcome.memory) {
+			eprintln!("part {}: {}", outcome.part, memory);
+		}
+	}
+
+	Ok(outcomes.iter().all(Outcome::is_ok))
+}
+
+fn main() -> ExitCode {
+	let command = parse_trace_args(env::args().skip(1)).and_then(|(verbosity, targets, rest)| {
+		trace::set_verbosity(verbosity);
+		trace::set_targets(targets);
+		parse_args(rest.into_iter())
+	});
+	let command = match command {
+		Ok(command) => command,
+		Err(e) => {
+			eprintln!("aoc: {}\n\n{}", e, USAGE);
+			return ExitCode::from(2);
+		}
+	};
+
+	let result = match command {
+		Command::Help => {
+			println!("{}", USAGE);
+			Ok(true)
+		}
+		Command::Run {
+			solution, parts, source, timeout, format, show_progress, checkpoint, resume,
+		} => {
+			progress::set_enabled(show_progress);
+			checkpoint::set_path(checkpoint, resume);
+			run(solution, &parts, &source, timeout, format)
+		}
+		Command::Batch { solution, parts, dir, timeout, format } =>
+			batch::run_batch(solution, &parts, &dir, timeout, format == Format::Json),
+		Command::Render { renderer, part, source, options } =>
+			source.read().and_then(|input| {
+				let mut screen = Screen::new(Box::new(std::io::stdout()), options);
+				(renderer.render)(&input, part, &mut screen)
+			}).map(|()| true),
+		Command::Export { exporter, part, source, dir } =>
+			source.read().and_then(|input| {
+				let mut images = Images::new(&dir, exporter.day);
+				(exporter.export)(&input, part, &mut images)?;
+				for path in images.written() {
+					println!("{}", path.display());
+				}
+				Ok(true)
+			}),
+		Command::RunAll { part, example, jobs, timeout, format } =>
+			all::run_all(part, example, jobs, timeout, format == Format::Json),
+		Command::Verify { day, answers, timeout } =>
+			Ledger::load(&answers).and_then(|ledger| verify::verify(&ledger, day, timeout)),
+		Command::Bench { solutions, path, example, runs, history } =>
+			bench::bench(&solutions, path.as_deref(), example, runs, &history),
+		Command::Serve { timeout } =>
+			serve::serve(std::io::stdin().lock(), std::io::stdout().lock(), timeout).map(|()| true),
+		Command::Generate { day, seed, size } =>
+			generate::generate(day, seed, size).map(|input| {
+				print!("{}", input);
+				true
+			}),
+	};
+
+	match result {
+		Ok(true) => ExitCode::SUCCESS,
+		Ok(false) => ExitCode::FAILURE,
+		Err(e) => {
+			eprintln!("aoc: {:#}", e);
+			ExitCode::FAILURE
+		}
+	}
+}
@@ -0,0 +1,40 @@
+use std::fmt;
+
+// a plain text table with columns padded to their widest cell
+pub struct Table {
+	header: Vec<&'static str>,
+	rows: Vec<Vec<String>>,
+}
+
+impl Table {
+	pub fn new(header: &[&'static str]) -> Table {
+		Table { header: header.to_vec(), rows: Vec::new() }
+	}
+
+	pub fn row(&mut self, cells: Vec<String>) {
+		assert_eq!(cells.len(), self.header.len());
+		self.rows.push(cells);
+	}
+}
+
+impl fmt::Display for Table {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let mut widths: Vec<_> = self.header.iter().map(|h| h.len()).collect();
+		for row in &self.rows {
+			for (width, cell) in widths.iter_mut().zip(row) {
+				*width = usize::max(*width, cell.chars().count());
+			}
+		}
+
+		let header = self.header.iter().map(|h| h.to_string()).collect();
+		for row in std::iter::once(&header).chain(&self.rows) {
+			let mut line = String::new();
+			for (cell, &width) in row.iter().zip(&widths) {
+				line += &format!("{:width$}  ", cell, width = width);
+			}
+			writeln!(f, "{}", line.trim_end())?;
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,79 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use eyre::Result;
+
+use advent_of_code_2021::answers::Ledger;
+use advent_of_code_2021::cancel::{self, TimedOut};
+use advent_of_code_2021::days::DAYS;
+use advent_of_code_2021::input;
+
+use crate::table::Table;
+
+// runs every solution on every input we have answers for, plus any default
+// inputs lying around, and prints a table of the results. returns whether
+// nothing was wrong, failed or ran out of time.
+pub fn verify(ledger: &Ledger, day: Option<u32>, timeout: Option<Duration>) -> Result<bool> {
+	let mut table = Table::new(&["day", "impl", "part", "input", "expected", "answer", "status"]);
+	let mut all_ok = true;
+
+	for solution in DAYS.iter().filter(|s| day.is_none_or(|d| d == s.day)) {
+		let mut inputs: Vec<PathBuf> =
+			ledger.inputs(solution.day).map(Path::to_path_buf).collect();
+		for example in [false, true] {
+			let path = input::default_path(solution.day, example);
+			if !inputs.contains(&path) && path.exists() {
+				inputs.push(path);
+			}
+		}
+
+		for path in inputs {
+			let parsed = input::read_file(&path)
+				.and_then(|input| (solution.parse)(&input));
+
+			for &part in solution.parts {
+				let expected = ledger.expected(&path, part);
+				let (answer, status) = match &parsed {
+					_ if !path.exists() => (String::new(), "no input"),
+					Err(e) => (first_line(e), "ERROR"),
+					Ok(parsed) => match cancel::with_timeout(timeout, || (solution.solve)(parsed, part)) {
+						Err(e) if e.downcast_ref::<TimedOut>().is_some() => (first_line(&e), "TIMEOUT"),
+						Err(e) => (first_line(&e), "ERROR"),
+						Ok(answer) => {
+							let status = match expected {
+								None => "missing answer",
+								Some(expected) if expected == answer => "ok",
+								Some(_) => "MISMATCH",
+							};
+							(answer, status)
+						}
+					},
+				};
+
+				if matches!(status, "ERROR" | "MISMATCH" | "TIMEOUT") {
+					all_ok = false;
+				}
+
+				table.row(vec![
+					solution.day.to_string(),
+					solution.name.to_string(),
+					part.to_string(),
+					path.display().to_string(),
+					expected.unwrap_or("-").to_string(),
+					answer,
+					status.to_string(),
+				]);
+			}
+		}
+	}
+
+	print!("{}", table);
+
+	Ok(all_ok)
+}
+
+// errors that point into the input go on for a few lines, which a table row
+// can't hold
+fn first_line(e: &eyre::Report) -> String {
+	format!("{:#}", e).lines().next().unwrap_or_default().to_string()
+}
@@ -0,0 +1,68 @@
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+// time limits for the solutions. whoever runs a solution sets a deadline for
+// the current thread, and the solutions check it in their outer loops, giving
+// up with a TimedOut error that says how far they got. solutions that start
+// threads of their own hand the deadline on to them.
+
+thread_local! {
+	static DEADLINE: Cell<Option<Deadline>> = const { Cell::new(None) };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deadline {
+	at: Instant,
+	timeout: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedOut {
+	pub timeout: Duration,
+	pub progress: String,
+}
+
+impl fmt::Display for TimedOut {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "timed out after {:.2?}, {}", self.timeout, self.progress)
+	}
+}
+
+impl Error for TimedOut {}
+
+// puts the previous deadline back even if `f` panics
+struct Restore(Option<Deadline>);
+
+impl Drop for Restore {
+	fn drop(&mut self) {
+		DEADLINE.with(|d| d.set(self.0));
+	}
+}
+
+// runs `f` with a deadline `timeout` from now on this thread, or none
+pub fn with_timeout<T>(timeout: Option<Duration>, f: impl FnOnce() -> T) -> T {
+	with_deadline(timeout.map(|timeout| Deadline { at: Instant::now() + timeout, timeout }), f)
+}
+
+// runs `f` with the given deadline on this thread, like one from another
+// thread's deadline()
+pub fn with_deadline<T>(deadline: Option<Deadline>, f: impl FnOnce() -> T) -> T {
+	let _restore = Restore(DEADLINE.with(|d| d.replace(deadline)));
+	f()
+}
+
+// this thread's deadline, to pass on to the threads it starts
+pub fn deadline() -> Option<Deadline> {
+	DEADLINE.with(Cell::get)
+}
+
+// fails once the deadline has passed. the progress is only worked out then.
+pub fn check(progress: impl FnOnce() -> String) -> Result<(), TimedOut> {
+	match deadline() {
+		Some(Deadline { at, timeout }) if Instant::now() >= at =>
+			Err(TimedOut { timeout, progress: progress() }),
+		_ => Ok(()),
+	}
+}
@@ -0,0 +1,97 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+// lets long searches save their state to a file every so often, and pick up
+// from there next time. a checkpoint is only used for the same search it was
+// saved by, which the key says, like the day and what the input was.
+
+static CONFIG: RwLock<Option<Config>> = RwLock::new(None);
+
+const INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+struct Config {
+	path: PathBuf,
+	resume: bool,
+}
+
+// saves to the path from now on, and first loads from it if resuming
+pub fn set_path(path: Option<PathBuf>, resume: bool) {
+	*CONFIG.write().unwrap() = path.map(|path| Config { path, resume });
+}
+
+fn config() -> Option<Config> {
+	CONFIG.read().unwrap().clone()
+}
+
+#[derive(Serialize, Deserialize)]
+struct File<T> {
+	key: String,
+	state: T,
+}
+
+pub struct Checkpoint {
+	key: String,
+	last_save: Instant,
+}
+
+impl Checkpoint {
+	pub fn new(key: impl Into<String>) -> Checkpoint {
+		Checkpoint { key: key.into(), last_save: Instant::now() }
+	}
+
+	// the saved state, if resuming and the file was saved by the same search
+	pub fn load<T: DeserializeOwned>(&self) -> Result<Option<T>> {
+		let Some(Config { path, resume: true }) = config() else {
+			return Ok(None);
+		};
+		if !path.exists() {
+			return Ok(None);
+		}
+
+		let text = fs::read_to_string(&path)
+			.wrap_err_with(|| format!("couldn't read checkpoint {}", path.display()))?;
+		let file: File<serde_json::Value> = serde_json::from_str(&text)
+			.wrap_err_with(|| format!("bad checkpoint {}", path.display()))?;
+		if file.key != self.key {
+			crate::debug!("ignoring checkpoint for {:?}, not {:?}", file.key, self.key);
+			return Ok(None);
+		}
+
+		let state = serde_json::from_value(file.state)
+			.wrap_err_with(|| format!("bad checkpoint {}", path.display()))?;
+		Ok(Some(state))
+	}
+
+	// saves every few seconds, so it's cheap to call often
+	pub fn update<T: Serialize>(&mut self, state: &T) -> Result<()> {
+		match self.last_save.elapsed() >= INTERVAL {
+			true => self.save(state),
+			false => Ok(()),
+		}
+	}
+
+	pub fn save<T: Serialize>(&mut self, state: &T) -> Result<()> {
+		self.last_save = Instant::now();
+		let Some(Config { path, .. }) = config() else {
+			return Ok(());
+		};
+
+		let text = serde_json::to_string(&File { key: self.key.clone(), state })?;
+		write_atomically(&path, &text)
+			.wrap_err_with(|| format!("couldn't write checkpoint {}", path.display()))
+	}
+}
+
+// so that crashing halfway through writing doesn't lose the last checkpoint
+fn write_atomically(path: &Path, text: &str) -> std::io::Result<()> {
+	let mut tmp = path.as_os_str().to_owned();
+	tmp.push(".tmp");
+	fs::write(&tmp, text)?;
+	fs::rename(&tmp, path)
+}
@@ -0,0 +1,63 @@
+use eyre::{Result, WrapErr};
+use itertools::Itertools;
+
+use crate::diagnostic;
+use crate::generate::Rng;
+use crate::solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<i64>> {
+	diagnostic::lines(input)
+		.map(|line| line.wrap(line.text, line.text.parse::<i64>()
+			.wrap_err("that's not a number")))
+		.collect()
+}
+
+pub fn count_increases(numbers: &[i64]) -> i64 {
+	numbers.iter()
+		.tuple_windows()
+		.filter(|(a, b)| a < b)
+		.count() as i64
+}
+
+pub fn count_window_increases(numbers: &[i64]) -> i64 {
+	let triples = numbers.iter().tuple_windows::<(_, _, _)>();
+
+	// neighbouring windows share their middle two numbers, so comparing the
+	// ends is the same as comparing the sums, without risking an overflow
+	triples
+		.clone()
+		.zip(triples.skip(1))
+		.map(|((a1, _, _), (_, _, b3))| if a1 < b3 { 1 } else { 0 })
+		.sum()
+}
+
+// a random walk down, with the odd step back up
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let mut depth = rng.range(100..=200);
+	let mut out = String::new();
+	for _ in 0..size {
+		out += &format!("{}\n", depth);
+		depth = i64::max(0, depth + rng.range(-20..=30));
+	}
+	out
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+	type Input = Vec<i64>;
+	type Answer1 = i64;
+	type Answer2 = i64;
+
+	fn parse(input: &str) -> Result<Vec<i64>> {
+		parse(input)
+	}
+
+	fn part1(numbers: &Vec<i64>) -> Result<i64> {
+		Ok(count_increases(numbers))
+	}
+
+	fn part2(numbers: &Vec<i64>) -> Result<i64> {
+		Ok(count_window_increases(numbers))
+	}
+}
@@ -0,0 +1,147 @@
+use std::cell::Cell;
+use std::collections::BTreeSet;
+
+use eyre::{Result, eyre, bail};
+
+use crate::cancel;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::image::{self, Image, Images};
+use crate::render::Color;
+use crate::solution::{Part, Solution};
+
+pub fn parse(input: &str) -> Result<Grid<u32>> {
+	Grid::parse_with(input, |c| match c {
+		'1'..='9' => c.to_digit(10),
+		_ => None,
+	})
+}
+
+// the risk of entering a cell of the map repeated `scale` times each way,
+// one higher with every repetition and wrapping around from 9 to 1
+fn risk_at(risks: &Grid<u32>, (x, y): (usize, usize)) -> u32 {
+	let (w, h) = (risks.width(), risks.height());
+	let adjustment = (y / h + x / w) as u32;
+	((risks[(x % w, y % h)] + adjustment) - 1) % 9 + 1
+}
+
+// the lowest total risk from the top left to the bottom right, and the path
+// that has it, both ends included.
+// the set is keyed on cells we mutate, but only while they're not in the set
+#[allow(clippy::mutable_key_type)]
+pub fn safest_path(risks: &Grid<u32>, scale: usize) -> Result<(u32, Vec<(usize, usize)>)> {
+	let (w, h) = (risks.width(), risks.height());
+	if risks.is_empty() || scale == 0 {
+		bail!("empty map");
+	}
+	let state: Grid<(Cell<bool>, Cell<u32>)> =
+		Grid::new(w * scale, h * scale, (Cell::new(false), Cell::new(u32::MAX)));
+	let mut previous = Grid::new(w * scale, h * scale, None);
+
+	let mut unvisited: BTreeSet<(&Cell<u32>, usize, usize)> = BTreeSet::new();
+	for ((x, y), cell) in state.iter() {
+		unvisited.insert((&cell.1, x, y));
+	}
+
+	let (mut x, mut y) = (0usize, 0usize);
+	state[(x, y)].1.set(0);
+
+	let (target_x, target_y) = (state.width()-1, state.height()-1);
+
+	let mut visited = 0;
+	loop {
+		if visited % 1024 == 0 {
+			cancel::check(|| format!("visited {} of {} cells", visited, state.width() * state.height()))?;
+		}
+		visited += 1;
+
+		let c = &state[(x, y)];
+		crate::debug!("visiting {},{}: done={} risk={}", x, y, c.0.get(), c.1.get());
+
+		if x == target_x && y == target_y {
+			let mut path = vec![(x, y)];
+			while let Some(pos) = previous[*path.last().unwrap()] {
+				path.push(pos);
+			}
+			path.reverse();
+			return Ok((c.1.get(), path));
+		}
+
+		for (tx, ty) in state.neighbors4((x, y)) {
+			let n = &state[(tx, ty)];
+			let local_risk = risk_at(risks, (tx, ty));
+			crate::trace!("  neighbor {},{}: done={} risk={} local={}", tx, ty, n.0.get(), n.1.get(), local_risk);
+			if n.0.get() {
+				continue;
+			}
+
+			let new_total_risk = c.1.get() + local_risk;
+			crate::trace!("    {} / {}", n.1.get(), new_total_risk);
+			if new_total_risk < n.1.get() {
+				let r = (&n.1, tx, ty);
+				unvisited.remove(&r);
+				n.1.set(new_total_risk);
+				unvisited.insert(r);
+				previous[(tx, ty)] = Some((x, y));
+			}
+		}
+
+		c.0.set(true);
+		unvisited.remove(&(&c.1, x, y));
+		let n = unvisited.iter().next()
+			.ok_or_else(|| eyre!("no way to the bottom right"))?;
+		x = n.1;
+		y = n.2;
+	}
+}
+
+pub fn lowest_total_risk(risks: &Grid<u32>, scale: usize) -> Result<u32> {
+	Ok(safest_path(risks, scale)?.0)
+}
+
+// the risk map in shades of grey, brighter where it's riskier, with the
+// safest path through it in red. part 2 is the whole five by five map.
+pub fn export(input: &str, part: Part, images: &mut Images) -> Result<()> {
+	let risks = parse(input)?;
+	let scale = if part == Part::Two { 5 } else { 1 };
+	let (_, path) = safest_path(&risks, scale)?;
+	let mut image = Grid::from_fn(risks.width() * scale, risks.height() * scale, |x, y| {
+		[(risk_at(&risks, (x, y)) * 25) as u8; 3]
+	});
+	for pos in path {
+		image[pos] = image::rgb(Color::Red);
+	}
+	images.save(&Image::Rgb(image))
+}
+
+// a square map of size x size risk levels
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let mut out = String::new();
+	for _ in 0..size {
+		for _ in 0..size {
+			out += &rng.range(1..=9).to_string();
+		}
+		out.push('\n');
+	}
+	out
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+	type Input = Grid<u32>;
+	type Answer1 = u32;
+	type Answer2 = u32;
+
+	fn parse(input: &str) -> Result<Grid<u32>> {
+		parse(input)
+	}
+
+	fn part1(risks: &Grid<u32>) -> Result<u32> {
+		lowest_total_risk(risks, 1)
+	}
+
+	fn part2(risks: &Grid<u32>) -> Result<u32> {
+		lowest_total_risk(risks, 5)
+	}
+}
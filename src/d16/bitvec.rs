@@ -0,0 +1,158 @@
+use std::io::{self, Read};
+
+use ::bitvec::prelude::*;
+use eyre::{Result, bail};
+
+use crate::solution::Solution;
+use super::{Packet, Payload};
+
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+	buf: &'a BitSlice<u8, Msb0>,
+	// where buf is in the whole transmission, for errors
+	pos: usize,
+	depth: usize,
+}
+
+impl<'a> Scanner<'a> {
+	pub fn new(buf: &'a BitSlice<u8, Msb0>) -> Scanner<'a> {
+		Scanner { buf, pos: 0, depth: 0 }
+	}
+
+	fn take(&mut self, n: usize) -> Result<&'a BitSlice<u8, Msb0>> {
+		if n > self.buf.len() {
+			bail!("unexpected eof: needed {} bits, only {} left", n, self.buf.len());
+		}
+		let (taken, rest) = self.buf.split_at(n);
+		self.buf = rest;
+		self.pos += n;
+		Ok(taken)
+	}
+
+	fn scan_bits(&mut self, n: usize) -> Result<u64> {
+		assert!(n <= 64);
+		Ok(self.take(n)?.load_be())
+	}
+
+	fn scan_literal(&mut self) -> Result<u64> {
+		let mut out = 0u64;
+
+		loop {
+			let last = self.scan_bits(1)? == 0;
+			out <<= 4;
+			out |= self.scan_bits(4)?;
+			if last { break; }
+		}
+
+		Ok(out)
+	}
+
+	fn scan_operator(&mut self) -> Result<Vec<Packet>> {
+		let length_type_id = self.scan_bits(1)?;
+		crate::trace!("length type id {}", length_type_id);
+		match length_type_id {
+			0 => self.scan_operator_bit_length(),
+			1 => self.scan_operator_packet_count(),
+			n => bail!("unexpected length type id: {}", n),
+		}
+	}
+
+	fn scan_operator_bit_length(&mut self) -> Result<Vec<Packet>> {
+		let bit_length = self.scan_bits(15)? as usize;
+		let pos = self.pos;
+		let mut inner = Scanner { buf: self.take(bit_length)?, pos, depth: self.depth };
+		crate::trace!("inner: {}", inner.buf.iter().take(16)
+			.map(|b| if *b { '1' } else { '0' })
+			.collect::<String>());
+
+		let mut packets = Vec::new();
+		while !inner.buf.is_empty() {
+			packets.push(inner.scan_packet()?);
+		}
+		crate::trace!("done with inner");
+
+		Ok(packets)
+	}
+
+	fn scan_operator_packet_count(&mut self) -> Result<Vec<Packet>> {
+		let packet_count = self.scan_bits(11)?;
+		let mut packets = Vec::new();
+
+		for _ in 0..packet_count {
+			packets.push(self.scan_packet()?);
+		}
+
+		Ok(packets)
+	}
+
+	pub fn scan_packet(&mut self) -> Result<Packet> {
+		if self.depth > super::MAX_DEPTH {
+			bail!(super::too_deep(self.pos));
+		}
+		let version = self.scan_bits(3)?;
+		let packet_type = self.scan_bits(3)?;
+
+		let payload = match packet_type {
+			4 => Payload::Literal(self.scan_literal()?),
+			n => {
+				self.depth += 1;
+				let packets = self.scan_operator()?;
+				self.depth -= 1;
+				Payload::Operator(n, packets)
+			}
+		};
+
+		crate::debug!("version {}: {:?}", version, payload);
+
+		Ok(Packet { version, payload })
+	}
+}
+
+pub fn load_input(mut r: impl Read) -> Result<BitVec<u8, Msb0>> {
+	let mut bits = BitVec::<u8, Msb0>::new();
+	let mut buf = [0u8; 1024];
+
+	loop {
+		let n = match r.read(&mut buf) {
+			Ok(0) => break,
+			Ok(n) => n,
+			Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+			Err(e) => bail!(e),
+		};
+		let buf = &buf[..n];
+
+		for b in buf {
+			let b = b.to_ascii_uppercase();
+
+			let r = match b {
+				b'0'..=b'9' => b - b'0',
+				b'A'..=b'F' => b - b'A' + 10,
+				_ => bail!("unexpected non-hex-digit: {}", b),
+			};
+			let slice = &BitSlice::<u8, Msb0>::from_element(&r)[4..8];
+			bits.extend_from_bitslice(slice);
+		}
+	}
+
+	Ok(bits)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+	type Input = Packet;
+	type Answer1 = u64;
+	type Answer2 = u64;
+
+	fn parse(input: &str) -> Result<Packet> {
+		super::scan(input, |hex| Scanner::new(&load_input(hex.as_bytes())?).scan_packet())
+	}
+
+	fn part1(packet: &Packet) -> Result<u64> {
+		packet.version_sum()
+	}
+
+	fn part2(packet: &Packet) -> Result<u64> {
+		packet.eval()
+	}
+}
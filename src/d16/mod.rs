@@ -0,0 +1,228 @@
+use eyre::{Result, eyre, bail};
+
+use crate::diagnostic::{self, Line, Located};
+use crate::generate::Rng;
+
+pub mod nibble;
+pub mod bitvec;
+
+// packets nested in more others than this are taken to be garbage rather
+// than recursed into until the stack runs out. real inputs don't come close.
+pub const MAX_DEPTH: usize = 100;
+
+// the transmission is one line of hex digits. both scanners check the digits
+// as they go, but by then they don't know where they are in the input.
+pub fn hex(input: &str) -> Result<&str> {
+	transmission(input).map(|(_, text)| text)
+}
+
+fn transmission(input: &str) -> Result<(Line<'_>, &str)> {
+	let mut lines = diagnostic::lines(input).filter(|line| !line.text.trim().is_empty());
+	let line = lines.next().ok_or_else(|| eyre!("empty input"))?;
+	if let Some(extra) = lines.next() {
+		bail!(extra.error(extra.text, "expected a single line of hex digits"));
+	}
+
+	let text = line.text.trim();
+	if let Some((i, c)) = text.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+		let i = diagnostic::span(line.text, text).start + i;
+		bail!(line.error_at(i..i+c.len_utf8(), "expected a hex digit"));
+	}
+
+	Ok((line, text))
+}
+
+// scans the packet from the hex digits, with errors the scanner could place
+// as a column among the digits pointing into the line
+pub fn scan(input: &str, scan: impl FnOnce(&str) -> Result<Packet>) -> Result<Packet> {
+	let (line, text) = transmission(input)?;
+	match scan(text) {
+		Err(e) if e.is::<Located>() => line.wrap(text, Err(e)),
+		result => result,
+	}
+}
+
+// an error about the packet starting at the given bit
+pub fn too_deep(bit: usize) -> Located {
+	let digit = bit / 4;
+	Located {
+		columns: digit..digit + 1,
+		message: format!("packet nested in more than {} others", MAX_DEPTH),
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub enum ControlFlow {
+	Break,
+	Continue,
+}
+
+use ControlFlow::*;
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Packet {
+	pub version: u64,
+	pub payload: Payload,
+}
+
+impl Packet {
+	pub fn visit_packets<F>(&self, mut f: F) -> Result<ControlFlow>
+			where F: for<'a> FnMut(&'a Packet) -> Result<ControlFlow> {
+		fn inner<G>(self_: &Packet, f: &mut G) -> Result<ControlFlow>
+				where G: for<'a> FnMut(&'a Packet) -> Result<ControlFlow> {
+			if f(self_)? == Break {
+				return Ok(Break);
+			}
+
+			if let Payload::Operator(_, packets) = &self_.payload {
+				for packet in packets {
+					if inner(packet, f)? == Break {
+						return Ok(Break);
+					}
+				}
+			}
+
+			Ok(Continue)
+		}
+
+		inner(self, &mut f)
+	}
+
+	pub fn version_sum(&self) -> Result<u64> {
+		let mut versions_sum = 0;
+		self.visit_packets(|p| {
+			crate::trace!("version = {}", p.version);
+			versions_sum += p.version;
+			Ok(Continue)
+		})?;
+		Ok(versions_sum)
+	}
+
+	pub fn eval(&self) -> Result<u64> {
+		fn bin_pred(packets: &[u64], f: impl Fn(u64, u64) -> bool)
+				-> Result<u64> {
+			if packets.len() != 2 {
+					bail!("binary predicate has {} packets, not 2", packets.len());
+			}
+
+			let result = f(packets[0], packets[1]);
+			let result = if result { 1 } else { 0 };
+			Ok(result)
+		}
+
+		let result: u64 = match self.payload {
+			Payload::Literal(v) => v,
+			Payload::Operator(op, ref packets) => {
+				let values = packets.iter()
+					.map(|p| p.eval())
+					.collect::<Result<Vec<_>>>()?;
+				match op {
+					0 => values.iter().try_fold(0u64, |a, &b| a.checked_add(b))
+						.ok_or_else(|| eyre!("sum overflows"))?,
+					1 => values.iter().try_fold(1u64, |a, &b| a.checked_mul(b))
+						.ok_or_else(|| eyre!("product overflows"))?,
+					2 => *values.iter().min().ok_or_else(|| eyre!("no packets for min"))?,
+					3 => *values.iter().max().ok_or_else(|| eyre!("no packets for max"))?,
+					5 => bin_pred(&values, |a, b| a > b)?,
+					6 => bin_pred(&values, |a, b| a < b)?,
+					7 => bin_pred(&values, |a, b| a == b)?,
+					_ => bail!("unknown operation type {}", op),
+				}
+			}
+		};
+
+		Ok(result)
+	}
+
+	// the transmission for this packet, padded to whole hex digits
+	pub fn encode(&self) -> Result<String> {
+		let mut bits = Vec::new();
+		self.encode_bits(&mut bits)?;
+		while bits.len() % 4 != 0 {
+			bits.push(false);
+		}
+
+		Ok(bits.chunks(4)
+			.map(|nibble| nibble.iter().fold(0, |n, &b| n << 1 | b as u32))
+			.map(|n| char::from_digit(n, 16).unwrap_or('0').to_ascii_uppercase())
+			.collect())
+	}
+
+	fn encode_bits(&self, bits: &mut Vec<bool>) -> Result<()> {
+		fn push(bits: &mut Vec<bool>, value: u64, n: u32) {
+			bits.extend((0..n).rev().map(|i| value >> i & 1 == 1));
+		}
+
+		push(bits, self.version, 3);
+		match &self.payload {
+			Payload::Literal(v) => {
+				push(bits, 4, 3);
+				let groups = u32::max(1, (64 - v.leading_zeros()).div_ceil(4));
+				for i in (0..groups).rev() {
+					push(bits, (i > 0) as u64, 1);
+					push(bits, v >> (i * 4) & 0xf, 4);
+				}
+			}
+			Payload::Operator(op, packets) => {
+				push(bits, *op, 3);
+				let mut inner = Vec::new();
+				for packet in packets {
+					packet.encode_bits(&mut inner)?;
+				}
+
+				// the packet doesn't remember which length type it came with,
+				// so pick one that fits, going by the version when both do
+				let fits_count = packets.len() < 1 << 11;
+				let by_length = inner.len() < 1 << 15 && (!fits_count || self.version.is_multiple_of(2));
+				if by_length {
+					push(bits, 0, 1);
+					push(bits, inner.len() as u64, 15);
+				} else if fits_count {
+					push(bits, 1, 1);
+					push(bits, packets.len() as u64, 11);
+				} else {
+					bail!("{} sub-packets in {} bits don't fit either length type",
+						packets.len(), inner.len());
+				}
+				bits.extend(inner);
+			}
+		}
+
+		Ok(())
+	}
+}
+
+// a random expression of about `size` packets. products only ever multiply
+// small literals, so evaluating it can't overflow.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	fn literal(rng: &mut Rng, max: u64) -> Packet {
+		Packet { version: rng.below(8), payload: Payload::Literal(rng.below(max)) }
+	}
+
+	fn packet(rng: &mut Rng, budget: &mut usize, depth: usize) -> Packet {
+		*budget = budget.saturating_sub(1);
+		if depth > 0 && (*budget == 0 || depth == MAX_DEPTH || rng.chance(1, 3)) {
+			return literal(rng, 1 << 32);
+		}
+
+		let op = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+		let packets = match op {
+			1 => (0..rng.range(1..=3)).map(|_| literal(rng, 1 << 16)).collect(),
+			5..=7 => (0..2).map(|_| packet(rng, budget, depth + 1)).collect(),
+			_ => (0..rng.range(1..=4)).map(|_| packet(rng, budget, depth + 1)).collect(),
+		};
+		Packet { version: rng.below(8), payload: Payload::Operator(op, packets) }
+	}
+
+	let packet = packet(rng, &mut size.clone(), 0);
+	match packet.encode() {
+		Ok(hex) => format!("{}\n", hex),
+		Err(e) => panic!("generated an unencodable packet: {}", e),
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub enum Payload {
+	Literal(u64),
+	Operator(u64, Vec<Packet>),
+}
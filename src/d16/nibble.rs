@@ -0,0 +1,157 @@
+use eyre::{Result, eyre, bail};
+
+use crate::solution::Solution;
+use super::{Packet, Payload};
+
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+	buf: &'a [u8],
+	offset: u64,
+	consumed: u64,
+	depth: usize,
+}
+
+impl<'a> Scanner<'a> {
+	pub fn new(buf: &'a [u8]) -> Scanner<'a> {
+		let offset = 0;
+		let consumed = 0;
+
+		Scanner { buf, offset, consumed, depth: 0 }
+	}
+
+	fn read_hex(&self) -> Result<u64> {
+		let c = self.buf.first()
+				.ok_or_else(|| eyre!("unexpected eof"))?
+				.to_ascii_uppercase();
+
+		let r = match c {
+			x @ b'0'..=b'9' => x - b'0',
+			x @ b'A'..=b'F' => x - b'A' + 10,
+			x => bail!("unexpected non-hex-digit: {}", x),
+		};
+
+		Ok(r as u64)
+	}
+
+	fn scan_bits(&mut self, n: u64) -> Result<u64> {
+		assert!(n <= 64);
+
+		let mut out = 0u64;
+		let mut remaining = n;
+
+		while remaining > 0 {
+			let bits_available = 4 - self.offset;
+			let bits_count = u64::min(bits_available, remaining);
+			remaining -= bits_count;
+			let x = self.read_hex()?;
+			let mask = !(!0u64 << bits_available);
+			let x = (x & mask) >> (bits_available - bits_count);
+			self.offset += bits_count;
+			self.consumed += bits_count;
+			assert!(self.offset <= 4);
+			if self.offset == 4 {
+				self.buf = &self.buf[1..];
+				self.offset = 0;
+			}
+			out <<= bits_count;
+			out |= x;
+		}
+
+		crate::trace!("scan_bits({}) -> {:0>len$b}", n, out, len = n as usize);
+
+		Ok(out)
+	}
+
+	fn scan_literal(&mut self) -> Result<u64> {
+		let mut out = 0u64;
+
+		loop {
+			let last = self.scan_bits(1)? == 0;
+			out <<= 4;
+			out |= self.scan_bits(4)?;
+			if last { break; }
+		}
+
+		Ok(out)
+	}
+
+	fn scan_operator(&mut self) -> Result<Vec<Packet>> {
+		let length_type_id = self.scan_bits(1)?;
+		crate::trace!("length type id {}", length_type_id);
+		match length_type_id {
+			0 => self.scan_operator_bit_length(),
+			1 => self.scan_operator_packet_count(),
+			n => bail!("unexpected length type id: {}", n),
+		}
+	}
+
+	fn scan_operator_bit_length(&mut self) -> Result<Vec<Packet>> {
+		let bit_length = self.scan_bits(15)?;
+		let end = self.consumed + bit_length;
+		let mut packets = Vec::new();
+
+		while self.consumed < end {
+			packets.push(self.scan_packet()?);
+		}
+		if self.consumed != end {
+			bail!("sub-packets take up {} bits, more than the {} they're supposed to",
+				self.consumed - (end - bit_length), bit_length);
+		}
+
+		Ok(packets)
+	}
+
+	fn scan_operator_packet_count(&mut self) -> Result<Vec<Packet>> {
+		let packet_count = self.scan_bits(11)?;
+		let mut packets = Vec::new();
+
+		for _ in 0..packet_count {
+			packets.push(self.scan_packet()?);
+		}
+
+		Ok(packets)
+	}
+
+	pub fn scan_packet(&mut self) -> Result<Packet> {
+		if self.depth > super::MAX_DEPTH {
+			bail!(super::too_deep(self.consumed as usize));
+		}
+		let version = self.scan_bits(3)?;
+		let packet_type = self.scan_bits(3)?;
+		crate::trace!("packet at bit {}: version {}, type {}", self.consumed, version, packet_type);
+
+		let payload = match packet_type {
+			4 => Payload::Literal(self.scan_literal()?),
+			n => {
+				self.depth += 1;
+				let packets = self.scan_operator()?;
+				self.depth -= 1;
+				Payload::Operator(n, packets)
+			}
+		};
+
+		crate::debug!("version {}: {:?}", version, payload);
+
+		Ok(Packet { version, payload })
+	}
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+	type Input = Packet;
+	type Answer1 = u64;
+	type Answer2 = u64;
+
+	fn parse(input: &str) -> Result<Packet> {
+		super::scan(input, |hex| Scanner::new(hex.as_bytes()).scan_packet())
+	}
+
+	fn part1(packet: &Packet) -> Result<u64> {
+		packet.version_sum()
+	}
+
+	fn part2(packet: &Packet) -> Result<u64> {
+		packet.eval()
+	}
+}
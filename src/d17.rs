@@ -0,0 +1,319 @@
+use std::collections::HashSet;
+use std::iter;
+use std::ops::RangeInclusive;
+use std::str;
+use std::sync::OnceLock;
+
+use eyre::{Result, eyre, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::cancel;
+use crate::checkpoint::Checkpoint;
+use crate::diagnostic::{self, Located};
+use crate::generate::Rng;
+use crate::image::{Image, Images};
+use crate::progress::Progress;
+use crate::render::{Color, Render, Screen};
+use crate::solution::{Part, Solution};
+
+// the search goes as fast as the furthest coordinate n, and a throw that fast
+// goes up n·(n+1)/2, which has to fit in an i32
+pub const MAX_COORD: i32 = 50_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Target {
+	pub x: (i32, i32),
+	pub y: (i32, i32),
+}
+
+impl str::FromStr for Target {
+	type Err = eyre::Report;
+
+	// target area: x=20..30, y=-10..-5
+	fn from_str(s: &str) -> Result<Target> {
+		fn range(whole: &str, axis: &str) -> Result<(i32, i32)> {
+			let s = whole.trim();
+			let s = s.strip_prefix(axis)
+				.and_then(|s| s.strip_prefix('='))
+				.ok_or_else(|| Located::new(whole, s, format!("expected {}=...", axis)))?;
+			let coord = |c: &str| -> Result<i32> {
+				let n: i32 = diagnostic::locate(whole, c, c.parse())?;
+				if n.unsigned_abs() > MAX_COORD as u32 {
+					bail!(Located::new(whole, c, format!("too far away, at most {}", MAX_COORD)));
+				}
+				Ok(n)
+			};
+			match s.split_once("..") {
+				Some((a, b)) => Ok((coord(a)?, coord(b)?)),
+				None => bail!(Located::new(whole, s, "expected a range")),
+			}
+		}
+
+		let rest = s.trim().strip_prefix("target area:")
+			.ok_or_else(|| Located::new(s, s, "expected \"target area:\""))?;
+		let target = match rest.split_once(',') {
+			Some((x, y)) => Target {
+				x: diagnostic::locate(s, x, range(x, "x"))?,
+				y: diagnostic::locate(s, y, range(y, "y"))?,
+			},
+			None => bail!(Located::new(s, rest, "expected x and y ranges")),
+		};
+
+		if target.endless() {
+			bail!(Located::new(s, rest.trim(), "every throw straight up enough would hit this"));
+		}
+
+		Ok(target)
+	}
+}
+
+impl Target {
+	pub fn contains(&self, (x, y): (i32, i32)) -> bool {
+		x >= self.x.0 && x <= self.x.1 && y >= self.y.0 && y <= self.y.1
+	}
+
+	// a throw always falls back through the launch height, so if the target
+	// is across it and the probe can come to a stop within the target's x
+	// range, throwing higher and higher hits it without end
+	fn endless(&self) -> bool {
+		let across = self.y.0 <= 0 && 0 <= self.y.1;
+		let stops = (0..).map(|n: i64| n * (n + 1) / 2)
+			.take_while(|&x| x <= i64::max(self.x.0.abs() as i64, self.x.1.abs() as i64))
+			.any(|x| self.contains_x(x as i32) || self.contains_x(-x as i32));
+		across && stops
+	}
+
+	fn contains_x(&self, x: i32) -> bool {
+		x >= self.x.0 && x <= self.x.1
+	}
+
+	// every velocity that might hit. any faster sideways or down passes the
+	// target in the first step. any faster up than the target is high or deep
+	// jumps over it on the way back down, except at the launch height, which
+	// it gets back to after the probe has stopped moving sideways.
+	pub fn velocities(&self) -> (RangeInclusive<i32>, RangeInclusive<i32>) {
+		let dxs = i32::min(0, self.x.0)..=i32::max(0, self.x.1);
+		let mut max_dy = i32::max(self.y.0.abs(), self.y.1.abs());
+		if self.y.0 <= 0 && 0 <= self.y.1 {
+			max_dy = i32::max(max_dy, i32::max(self.x.0.abs(), self.x.1.abs()));
+		}
+		(dxs, i32::min(0, self.y.0)..=max_dy)
+	}
+}
+
+// every position of the probe after the launch until it's past the target,
+// and, if `to_peak`, has stopped going up
+fn steps(target: Target, mut dx: i32, mut dy: i32, to_peak: bool)
+		-> impl Iterator<Item = (i32, i32)> {
+	let mut x = 0;
+	let mut y = 0;
+
+	iter::from_fn(move || {
+		let past_x = (dx <= 0 && x < target.x.0) || (dx >= 0 && x > target.x.1);
+		let done = (past_x && (!to_peak || dy <= 0)) || (dy <= 0 && y < target.y.0);
+		if done {
+			return None;
+		}
+
+		x += dx;
+		y += dy;
+		dx -= dx.signum();
+		dy -= 1;
+
+		crate::trace!("{},{} {},{}", x, y, dx, dy);
+
+		Some((x, y))
+	})
+}
+
+// every position of the probe from the launch until it's past the target,
+// and whether one of them was in it
+pub fn trajectory(target: Target, dx: i32, dy: i32) -> (Vec<(i32, i32)>, bool) {
+	let path: Vec<_> = iter::once((0, 0)).chain(steps(target, dx, dy, true)).collect();
+	let success = path.iter().any(|&p| target.contains(p));
+	(path, success)
+}
+
+// the highest point of a throw that hits. the probe might be past the target
+// before it gets there, but it still gets there.
+pub fn throw(target: Target, dx: i32, dy: i32) -> Option<i32> {
+	let success = steps(target, dx, dy, false).any(|p| target.contains(p));
+	success.then_some(if dy > 0 { dy * (dy + 1) / 2 } else { 0 })
+}
+
+// the puzzle text's picture: the probe's path so far, the target, the
+// launch height, and a dot every ten units to judge distances by. y goes up.
+struct Picture {
+	target: Target,
+	path: HashSet<(i32, i32)>,
+	x: (i32, i32),
+	y: (i32, i32),
+}
+
+impl Picture {
+	// sized for the whole path, so the frames of a throw all line up
+	fn new(target: Target, whole: &[(i32, i32)], path: &[(i32, i32)]) -> Picture {
+		let xs = whole.iter().map(|&(x, _)| x).chain([target.x.0, target.x.1]);
+		let ys = whole.iter().map(|&(_, y)| y).chain([target.y.0, target.y.1]);
+		Picture {
+			target,
+			path: path.iter().copied().collect(),
+			x: (xs.clone().min().unwrap_or(0), xs.max().unwrap_or(0)),
+			y: (ys.clone().min().unwrap_or(0), ys.max().unwrap_or(0)),
+		}
+	}
+}
+
+impl Render for Picture {
+	fn size(&self) -> (usize, usize) {
+		((self.x.1 - self.x.0 + 1) as usize, (self.y.1 - self.y.0 + 1) as usize)
+	}
+
+	fn cell(&self, x: usize, y: usize) -> (char, Option<Color>) {
+		let x = self.x.0 + x as i32;
+		let y = self.y.1 - y as i32;
+		if self.path.contains(&(x, y)) {
+			('x', Some(Color::Yellow))
+		} else if self.target.contains((x, y)) {
+			('#', Some(Color::Green))
+		} else if y == 0 {
+			('-', Some(Color::Blue))
+		} else if x % 10 == 0 || y % 10 == 0 {
+			('.', Some(Color::Gray))
+		} else {
+			(' ', None)
+		}
+	}
+}
+
+fn highest_throw(input: &str) -> Result<(Target, Vec<(i32, i32)>)> {
+	let target = Day17::parse(input)?.target;
+	let (max, _) = search(target)?;
+	let (_, dx, dy) = max.ok_or_else(|| eyre!("no throw hits the target"))?;
+	let (path, _) = trajectory(target, dx, dy);
+	Ok((target, path))
+}
+
+// the highest throw, one step at a time
+pub fn render(input: &str, _: Part, screen: &mut Screen) -> Result<()> {
+	let (target, path) = highest_throw(input)?;
+	for i in 1..=path.len() {
+		screen.frame(&Picture::new(target, &path, &path[..i]))?;
+	}
+	Ok(())
+}
+
+// the whole of the highest throw in one image
+pub fn export(input: &str, _: Part, images: &mut Images) -> Result<()> {
+	let (target, path) = highest_throw(input)?;
+	images.save(&Image::from_render(&Picture::new(target, &path, &path)))
+}
+
+pub type Throw = (i32, i32, i32);
+
+// how far the search got, for checkpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Search {
+	next_dx: i32,
+	max: Option<Throw>,
+	successes: i32,
+}
+
+// returns the highest throw as (max y, dx, dy) and the number of throws that
+// hit the target
+pub fn search(target: Target) -> Result<(Option<Throw>, i32)> {
+	let mut checkpoint = Checkpoint::new(format!("d17 {:?}", target));
+	let (dxs, dys) = target.velocities();
+	let (first, end) = (*dxs.start(), dxs.end() + 1);
+	let mut search: Search = checkpoint.load()?
+		.unwrap_or(Search { next_dx: first, max: None, successes: 0 });
+	let total = (end - first) as u64;
+	let mut progress = Progress::new("d17", total, (search.next_dx - first) as u64);
+
+	for dx in search.next_dx..end {
+		search.next_dx = dx;
+		let tried = (dx - first) as u64;
+		if let Err(e) = cancel::check(|| format!("tried {} of {} x velocities, {} hits so far",
+				tried, total, search.successes)) {
+			checkpoint.save(&search)?;
+			bail!(e);
+		}
+		checkpoint.update(&search)?;
+		progress.update(tried);
+
+		for dy in dys.clone() {
+			if let Some(y) = throw(target, dx, dy) {
+				search.successes = search.successes.checked_add(1)
+					.ok_or_else(|| eyre!("too many throws hit the target to count"))?;
+				crate::debug!("hit with {},{}", dx, dy);
+				if search.max.is_none_or(|(max_y, _, _)| max_y < y) {
+					search.max = Some((y, dx, dy));
+				}
+			}
+		}
+	}
+
+	search.next_dx = end;
+	checkpoint.save(&search)?;
+	progress.finish();
+
+	Ok((search.max, search.successes))
+}
+
+// the search takes about the square of how far away the target is, so much
+// further than this it would take minutes
+const MAX_SIZE: usize = 3000;
+
+// a target below and to the right, further away the bigger the size
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let size = size.clamp(10, MAX_SIZE) as i64;
+	let x0 = rng.range(size / 2..=size);
+	let y0 = rng.range(-size..=-size / 2);
+	let x1 = x0 + rng.range(0..=size / 3);
+	let y1 = y0 + rng.range(0..=size / 3);
+	format!("target area: x={}..{}, y={}..{}\n", x0, x1, y0, y1)
+}
+
+// the target, and what searching it found, once either part has
+pub struct Probe {
+	pub target: Target,
+	found: OnceLock<(Option<Throw>, i32)>,
+}
+
+impl Probe {
+	// a search that failed or timed out is tried again next time
+	fn search(&self) -> Result<(Option<Throw>, i32)> {
+		if let Some(&found) = self.found.get() {
+			return Ok(found);
+		}
+		let found = search(self.target)?;
+		Ok(*self.found.get_or_init(|| found))
+	}
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+	type Input = Probe;
+	type Answer1 = i32;
+	type Answer2 = i32;
+
+	fn parse(input: &str) -> Result<Probe> {
+		let target = diagnostic::lines(input)
+			.find(|line| !line.text.trim().is_empty())
+			.ok_or_else(|| eyre!("empty input"))?
+			.parse()?;
+		Ok(Probe { target, found: OnceLock::new() })
+	}
+
+	fn part1(probe: &Probe) -> Result<i32> {
+		let (max, _) = probe.search()?;
+		let (max_y, _, _) = max.ok_or_else(|| eyre!("no throw hits the target"))?;
+		Ok(max_y)
+	}
+
+	fn part2(probe: &Probe) -> Result<i32> {
+		let (_, successes) = probe.search()?;
+		Ok(successes)
+	}
+}
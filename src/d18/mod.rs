@@ -0,0 +1,29 @@
+// two independent takes on snailfish math: an actual tree of boxed nodes, and
+// rewriting the textual representation in place
+pub mod tree;
+pub mod string;
+
+use crate::generate::Rng;
+
+// reduced numbers only, like the puzzle input: no pair nested inside four
+// others and no regular number above 9
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	fn element(rng: &mut Rng, depth: usize, out: &mut String) {
+		if depth == 0 || (depth < 4 && rng.chance(1, 2)) {
+			out.push('[');
+			element(rng, depth + 1, out);
+			out.push(',');
+			element(rng, depth + 1, out);
+			out.push(']');
+		} else {
+			out.push_str(&rng.range(0..=9).to_string());
+		}
+	}
+
+	let mut out = String::new();
+	for _ in 0..size {
+		element(rng, 0, &mut out);
+		out.push('\n');
+	}
+	out
+}
@@ -0,0 +1,128 @@
+use eyre::{Result, eyre, WrapErr};
+
+use crate::cancel;
+use crate::diagnostic;
+use crate::solution::Solution;
+
+fn digit(c: char) -> bool { c.is_ascii_digit() }
+fn not_digit(c: char) -> bool { !c.is_ascii_digit() }
+fn atoi(s: &str) -> Result<i32> { s.parse().wrap_err_with(|| format!("bad number {:?}", s)) }
+fn abr(s: &str) -> Result<Option<(i32, i32, &str)>> {
+	let k = s.find(']').ok_or_else(|| eyre!("unclosed pair in {:?}", s))?;
+	if s[..k].find('[').is_some() { return Ok(None); }
+	let c = s[..k].find(',').ok_or_else(|| eyre!("pair without a comma in {:?}", s))?;
+	let a: i32 = atoi(&s[..c])?;
+	let b: i32 = atoi(&s[c+1..k])?;
+	Ok(Some((a, b, &s[k+1..])))
+}
+
+pub fn add(a: &str, b: &str) -> Result<String> {
+	let mut s = format!("[{},{}]", a, b);
+	crate::debug!("adding => {s}");
+	'outer:
+	loop {
+		let mut depth = 0;
+		for i in 0..s.len() {
+			match s.as_bytes()[i] {
+				b']' => depth -= 1,
+				b'[' => {
+					depth += 1;
+					if depth <= 4 { continue; }
+					if let Some((a, b, r)) = abr(&s[i+1..])? {
+						let mut r = r.to_string();
+						s.truncate(i);
+						if let Some(j) = s.rfind(digit) {
+							let k = s[..j].rfind(not_digit).unwrap_or(0);
+							s = format!("{}{}{}", &s[..k+1], atoi(&s[k+1..j+1])? + a, &s[j+1..]);
+						}
+						if let Some(j) = r.find(digit) {
+							let k = r[j..].find(not_digit).unwrap_or(r.len());
+							r = format!("{}{}{}", &r[..j], atoi(&r[j..][..k])? + b, &r[j+k..]);
+						}
+						s += "0";
+						s += &r;
+						crate::trace!("explode => {s}");
+						continue 'outer;
+					}
+				}
+				_ => (),
+			}
+		}
+		let mut i = 0;
+		while let Some(j) = s[i..].find(digit) {
+			i += j;
+			let k = s[i..].find(not_digit).unwrap_or(s[i..].len());
+			if k > 1 {
+				let a: i32 = atoi(&s[i..][..k])?;
+				s = format!("{}[{},{}]{}", &s[..i], a/2, a-a/2, &s[i..][k..]);
+				crate::trace!("split => {s}");
+				continue 'outer;
+			}
+			i += k;
+		}
+		return Ok(s);
+	}
+}
+
+pub fn magnitude(s: &str) -> Result<i32> {
+	let mut i = 0;
+	while let Some(j) = s[i..].find('[') {
+		i += j;
+		if let Some((a, b, r)) = abr(&s[i+1..])? {
+			let s = format!("{}{}{}", &s[..i], a*3+b*2, r);
+			return magnitude(&s);
+		}
+		i += 1;
+	}
+
+	atoi(s)
+}
+
+// the string juggling above trusts its input to be well-formed, so the tree
+// parser checks it first
+pub fn parse(input: &str) -> Result<Vec<String>> {
+	diagnostic::lines(input).map(|line| {
+		line.parse::<super::tree::Node>()?;
+		Ok(line.text.to_string())
+	}).collect()
+}
+
+// two different numbers, though they may be equal
+pub fn max_magnitude(numbers: &[String]) -> Result<Option<i32>> {
+	let mut max = None;
+	for (i, a) in numbers.iter().enumerate() {
+		cancel::check(|| format!("added {} of {} numbers to all the others", i, numbers.len()))?;
+		for (j, b) in numbers.iter().enumerate() {
+			if i != j {
+				max = max.max(Some(magnitude(&add(a, b)?)?));
+			}
+		}
+	}
+	Ok(max)
+}
+
+pub fn sum(numbers: Vec<String>) -> Result<String> {
+	let mut numbers = numbers.into_iter();
+	let first = numbers.next().unwrap_or_else(|| "0".to_string());
+	numbers.try_fold(first, |a, b| add(&a, &b))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+	type Input = Vec<String>;
+	type Answer1 = i32;
+	type Answer2 = i32;
+
+	fn parse(input: &str) -> Result<Vec<String>> {
+		parse(input)
+	}
+
+	fn part1(numbers: &Vec<String>) -> Result<i32> {
+		magnitude(&sum(numbers.clone())?)
+	}
+
+	fn part2(numbers: &Vec<String>) -> Result<i32> {
+		max_magnitude(numbers)?.ok_or_else(|| eyre!("need at least two numbers"))
+	}
+}
@@ -1,12 +1,15 @@
 use std::ops;
 use std::fmt;
 use std::str;
-use std::io::{self, BufRead, BufReader};
 
-use eyre::{Result, Report, bail};
+use eyre::{Result, Report, eyre, bail};
+
+use crate::cancel;
+use crate::diagnostic::{self, Located};
+use crate::solution::Solution;
 
 #[derive(Debug, Clone)]
-enum Node {
+pub enum Node {
 	Number(i32),
 	Pair(Box<Node>, Box<Node>),
 }
@@ -14,12 +17,12 @@ enum Node {
 use Node::*;
 
 impl Node {
-	fn maybe_reduce(&mut self) -> bool {
+	pub fn maybe_reduce(&mut self) -> bool {
 		let mut did_anything = false;
 
 		while self.maybe_explode() || self.maybe_split() {
 			did_anything = true;
-			//eprintln!("   reducing {}", self);
+			crate::trace!("reducing {}", self);
 		}
 
 		did_anything
@@ -56,7 +59,7 @@ impl Node {
 			return Some((l2.and_then(|l2| l.maybe_add_left(l2)), r2));
 		}
 
-		return None;
+		None
 	}
 
 	fn maybe_add_left(&mut self, v: i32) -> Option<i32> {
@@ -93,7 +96,7 @@ impl Node {
 		}
 	}
 
-	fn magnitude(&self) -> i32 {
+	pub fn magnitude(&self) -> i32 {
 		match self {
 			&Number(n) => n,
 			Pair(l, r) => l.magnitude() * 3 + r.magnitude() * 2,
@@ -101,34 +104,50 @@ impl Node {
 	}
 }
 
+// input numbers are already reduced: no pair nested in four others, and no
+// regular number over 9. that also keeps the arithmetic from overflowing.
 struct Scanner<'a> {
 	buf: &'a [u8],
+	len: usize,
+	depth: usize,
 }
 
 impl Scanner<'_> {
-	fn new(buf: &str) -> Scanner {
+	fn new(buf: &str) -> Scanner<'_> {
 		let buf = buf.as_bytes();
-		Scanner { buf }
+		Scanner { buf, len: buf.len(), depth: 0 }
+	}
+
+	fn pos(&self) -> usize {
+		self.len - self.buf.len()
+	}
+
+	// an error about the next byte
+	fn error(&self, message: impl fmt::Display) -> Located {
+		let pos = self.pos();
+		Located { columns: pos..pos + usize::min(1, self.buf.len()), message: message.to_string() }
 	}
 
 	fn expect_byte(&mut self, b: u8) -> Result<()> {
-		let c = self.scan_byte()?;
+		let c = self.peek_byte()?;
 		if c != b {
-			bail!("unexpected character {:?}, expected {:?}", c, b);
+			bail!(self.error(format!("unexpected character {:?}, expected {:?}",
+				c as char, b as char)));
 		}
+		self.scan_byte()?;
 		Ok(())
 	}
 
 	fn expect_eof(&mut self) -> Result<()> {
 		match self.buf.is_empty() {
 			true => Ok(()),
-			false => bail!("expected EOF"),
+			false => bail!(self.error("expected end of line")),
 		}
 	}
 
 	fn peek_byte(&mut self) -> Result<u8> {
 		match self.buf.is_empty() {
-			true => bail!("unexpected EOF"),
+			true => bail!(self.error("unexpected end of line")),
 			false => Ok(self.buf[0]),
 		}
 	}
@@ -142,34 +161,45 @@ impl Scanner<'_> {
 	fn scan_tree(&mut self) -> Result<Node> {
 		let node = self.scan_node()?;
 		self.expect_eof()?;
-		return Ok(node);
+		Ok(node)
 	}
 
 	fn scan_node(&mut self) -> Result<Node> {
 		match self.peek_byte()? {
 			b'[' => self.scan_pair(),
 			c if c.is_ascii_digit() => self.scan_number(),
-			c => bail!("unexpected character: {:?}", c),
+			c => bail!(self.error(format!("unexpected character: {:?}", c as char))),
 		}
 	}
 
 	fn scan_pair(&mut self) -> Result<Node> {
+		if self.depth == 4 {
+			bail!(self.error("pair nested in four others, it should have exploded"));
+		}
 		self.expect_byte(b'[')?;
+		self.depth += 1;
 		let left = self.scan_node()?;
 		self.expect_byte(b',')?;
 		let right = self.scan_node()?;
 		self.expect_byte(b']')?;
+		self.depth -= 1;
 
 		Ok(Pair(Box::new(left), Box::new(right)))
 	}
 
 	fn scan_number(&mut self) -> Result<Node> {
-		let mut result = 0;
-		while self.peek_byte()?.is_ascii_digit() {
-			result = result * 10 + (self.scan_byte().unwrap() - b'0') as i32;
+		let start = self.pos();
+		let digits = self.buf.iter().take_while(|b| b.is_ascii_digit()).count();
+		let number = &self.buf[..digits];
+		self.buf = &self.buf[digits..];
+
+		match number {
+			&[d] => Ok(Number((d - b'0') as i32)),
+			_ => bail!(Located {
+				columns: start..self.pos(),
+				message: "number over 9, it should have split".to_string(),
+			}),
 		}
-
-		Ok(Number(result))
 	}
 }
 
@@ -208,27 +238,24 @@ impl ops::Add for &Node {
 	}
 }
 
-fn main() -> Result<()> {
-	let mut numbers: Vec<Node> = Vec::new();
-
-	for line in BufReader::new(io::stdin()).lines() {
-		let line = line?;
+pub fn parse(input: &str) -> Result<Vec<Node>> {
+	diagnostic::lines(input).map(|line| {
 		let mut number: Node = line.parse()?;
-		eprintln!("read number: {number}");
-		if number.maybe_reduce(){ 
-			eprintln!("  reduce to: {number}");
-		}
-		numbers.push(number);
-	}
-
-	// for number in &numbers {
-	// 	println!("{number}\n{number:?}\n\n");
-	// }
+		number.maybe_reduce();
+		Ok(number)
+	}).collect()
+}
 
+pub fn max_magnitude(numbers: &[Node]) -> Result<Option<i32>> {
 	let mut max = None;
-	
-	for a in &numbers {
-		for b in &numbers {
+
+	// two different numbers, though they may be equal
+	for (i, a) in numbers.iter().enumerate() {
+		cancel::check(|| format!("added {} of {} numbers to all the others", i, numbers.len()))?;
+		for (j, b) in numbers.iter().enumerate() {
+			if i == j {
+				continue;
+			}
 			let m = (a + b).magnitude();
 			if max.is_none() || max.unwrap() < m {
 				max = Some(m);
@@ -236,10 +263,29 @@ fn main() -> Result<()> {
 		}
 	}
 
-	println!("max magnitude: {:?}", max);
+	Ok(max)
+}
+
+pub fn sum(numbers: Vec<Node>) -> Node {
+	numbers.into_iter().reduce(|a, b| a + b).unwrap_or(Number(0))
+}
+
+pub struct Day18;
 
-	let number = numbers.into_iter().reduce(|a, b| a + b).unwrap_or(Number(0));
-	println!("sum: {}\nsum magnitude: {}", number, number.magnitude());
+impl Solution for Day18 {
+	type Input = Vec<Node>;
+	type Answer1 = i32;
+	type Answer2 = i32;
 
-	Ok(())
+	fn parse(input: &str) -> Result<Vec<Node>> {
+		parse(input)
+	}
+
+	fn part1(numbers: &Vec<Node>) -> Result<i32> {
+		Ok(sum(numbers.clone()).magnitude())
+	}
+
+	fn part2(numbers: &Vec<Node>) -> Result<i32> {
+		max_magnitude(numbers)?.ok_or_else(|| eyre!("need at least two numbers"))
+	}
 }
@@ -0,0 +1,313 @@
+use std::iter;
+use std::thread;
+use std::cmp::Ordering::*;
+use std::collections::{BTreeSet, HashSet};
+use std::sync::Arc;
+
+use eyre::{Result, eyre, bail};
+
+use crate::cancel::{self, TimedOut};
+use crate::diagnostic;
+use crate::generate::Rng;
+use crate::vector::{M3, V3, Vector};
+use crate::solution::Solution;
+
+pub fn rotations() -> [M3; 24] {
+	let id: M3 = [
+		[1, 0, 0],
+		[0, 1, 0],
+		[0, 0, 1],
+	].into();
+
+	let r_x: M3 = [
+		[1, 0, 0],
+		[0, 0, 1],
+		[0, -1, 0],
+	].into();
+
+	let r_y: M3 = [
+		[0, 0, -1],
+		[0, 1, 0],
+		[1, 0, 0],
+	].into();
+
+	let r_z = [
+		[ 0, 1, 0],
+		[-1, 0, 0],
+		[ 0, 0, 1],
+	].into();
+
+	let r1s: &[M3] = &[
+		id,
+		r_y,
+		r_y * r_y,
+		r_y * r_y * r_y,
+		r_z,
+		r_z * r_z * r_z,
+	];
+
+	let mut o = [id; 24];
+
+	for (i, &r1) in r1s.iter().enumerate() {
+		let mut m = r1;
+
+		for j in 0..4 {
+			o[i*4+j] = m;
+			m = r_x * m;
+		}
+	}
+
+	o
+}
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Beacon {
+	pub coords: V3,
+	pub neighbors: Vec<V3>,
+}
+
+#[inline]
+pub fn neighbors_for_beacon<I: Iterator<Item=V3>>(coords: V3, all: I)
+		-> Vec<V3> {
+	let mut neighbors: Vec<_> = all.filter_map(|other|
+		match coords == other {
+			true => None,
+			false => Some(other - coords),
+		}).collect();
+	neighbors.sort();
+	neighbors
+}
+
+pub fn beacons_with_neighbors(beacon_coords: &[V3]) -> Vec<Beacon> {
+	beacon_coords.iter().map(|&coords| {
+		let neighbors =
+			neighbors_for_beacon(coords, beacon_coords.iter().cloned());
+		Beacon { coords, neighbors }
+	}).collect()
+}
+
+// xs, ys need to be sorted
+#[inline]
+pub fn count_matches<'a, T, I>(xs: I, ys: I) -> u32
+		where T: 'a+Ord, I: IntoIterator<Item=&'a T> {
+	let mut xs = xs.into_iter();
+	let mut ys = ys.into_iter();
+	let mut x = xs.next();
+	let mut y = ys.next();
+	let mut count = 0;
+	loop {
+		match x.and_then(|x| y.map(|y| x.cmp(y))) {
+			None => return count,
+			Some(Less) => x = xs.next(),
+			Some(Greater) => y = ys.next(),
+			Some(Equal) => {
+				x = xs.next();
+				y = ys.next();
+				count += 1;
+			}
+		}
+	}
+}
+
+pub const MAX_COORD: u64 = 1_000_000;
+
+pub fn parse(input: &str) -> Result<Vec<Vec<V3>>> {
+	let mut scanner_beacon_coords = Vec::new();
+	for line in diagnostic::lines(input) {
+		if line.text.starts_with("--- ") {
+			scanner_beacon_coords.push(Vec::new());
+		} else if line.text.is_empty() {
+			continue;
+		} else {
+			let coords: V3 = line.parse()?;
+			// beacons are only seen from up to 1000 away, and anything a lot
+			// further out could overflow once scanners get chained
+			if coords.0.iter().any(|c| c.unsigned_abs() > MAX_COORD) {
+				bail!(line.error(line.text, format!("beacon further than {} away", MAX_COORD)));
+			}
+			scanner_beacon_coords
+				.last_mut()
+				.ok_or_else(|| line.error(line.text, "beacon before the first scanner"))?
+				.push(coords);
+		}
+	}
+
+	Ok(scanner_beacon_coords)
+}
+
+// returns all beacon and scanner positions relative to the last scanner
+pub fn locate(scanners: &[Vec<V3>]) -> Result<(HashSet<V3>, Vec<V3>)> {
+	let mut remaining_areas: Vec<Vec<Beacon>> =
+		scanners.iter().map(|beacon_coords|
+			beacons_with_neighbors(beacon_coords)).collect();
+
+	let initial_area = remaining_areas.pop()
+		.ok_or_else(|| eyre!("no scanners, rip"))?;
+
+	let mut normalized_beacons: HashSet<_> =
+		initial_area.iter().map(|beacon| beacon.coords).collect();
+
+	let mut reference_areas = Arc::new(vec![initial_area]);
+
+	let mut normalized_scanners = vec![[0, 0, 0].into()];
+
+	let rotations = rotations();
+
+	while !remaining_areas.is_empty() {
+		let located = scanners.len() - remaining_areas.len();
+		let progress = || format!("{} of {} scanners located", located, scanners.len());
+		cancel::check(progress)?;
+		let mut new_normalized_areas = Vec::new();
+		crate::debug!("unconnected scanners left: {}", remaining_areas.len());
+
+		// move all remaining areas into individual threads, leaving
+		// remaining_areas empty. they all give up at the same deadline.
+		let deadline = cancel::deadline();
+		let results = remaining_areas.drain(..).map(|candidate_area| {
+			let reference_areas = reference_areas.clone();
+			let progress = progress();
+			thread::spawn(move || cancel::with_deadline(deadline, || -> Result<_, TimedOut> {
+				for candidate_beacon in &candidate_area {
+					cancel::check(|| progress.clone())?;
+					for r in rotations {
+						let mut candidate_neighbors: Vec<_> =
+							candidate_beacon.neighbors.iter().cloned()
+								.map(|n| r * n).collect();
+						candidate_neighbors.sort();
+
+						for reference_beacon in reference_areas.iter().flatten() {
+							let matches = count_matches(
+								&reference_beacon.neighbors, &candidate_neighbors);
+
+							if matches < 11 {
+								continue;
+							}
+
+							// reconstruct normalized coordinates from the
+							// neighbor offsets we just normalized
+							let new_normalized_area: Vec<Beacon> =
+								beacons_with_neighbors(
+									&candidate_neighbors.iter()
+										.map(|&c| c + reference_beacon.coords)
+										.chain(iter::once(reference_beacon.coords))
+										.collect::<Vec<_>>());
+
+							let normalized_coords = r * candidate_beacon.coords;
+							let normalized_scanner =
+								reference_beacon.coords - normalized_coords;
+							return Ok((None, Some((new_normalized_area, normalized_scanner))));
+						}
+					}
+				}
+				Ok((Some(candidate_area), None))
+			}))
+		}).collect::<Vec<_>>().into_iter()
+			.map(|t| Ok(t.join().map_err(|_| eyre!("a matching thread panicked"))??))
+			.collect::<Result<Vec<_>>>()?;
+
+		for (maybe_remaining, maybe_result) in results {
+			// if the thread normalized things, record them and use the
+			// normalized beacons in the next generation of reference areas
+			if let Some((new_normalized, new_scanner)) = maybe_result {
+				normalized_beacons.extend(new_normalized.iter()
+					.map(|beacon| beacon.coords));
+				new_normalized_areas.push(new_normalized);
+				normalized_scanners.push(new_scanner);
+			}
+
+			// if the thread didnn't normalize things, put the
+			// area back for the next round
+			if let Some(remaining) = maybe_remaining {
+				remaining_areas.push(remaining);
+			}
+		}
+
+		if new_normalized_areas.is_empty() {
+			bail!("rip, {} unmatched scanners left", remaining_areas.len());
+		}
+
+		reference_areas = Arc::new(new_normalized_areas);
+	}
+
+	Ok((normalized_beacons, normalized_scanners))
+}
+
+pub fn max_distance(scanners: &[V3]) -> Option<i64> {
+	scanners.iter().flat_map(|&s1|
+			scanners.iter().map(move |&s2|
+				s1.manhattan_distance(s2)))
+		.max()
+}
+
+// scanners in a random tree, each overlapping the one it branched off from
+// by at least 12 beacons, plus a few beacons only one scanner can see. the
+// real positions and the answers are logged with -v.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	fn point(rng: &mut Rng, lo: V3, hi: V3) -> V3 {
+		Vector([0, 1, 2].map(|i| rng.range(lo[i]..=hi[i])))
+	}
+
+	let range = Vector([1000; 3]);
+	let mut scanners = vec![V3::ZERO];
+	let mut beacons = BTreeSet::new();
+	while scanners.len() < size {
+		let parent = *rng.choose(&scanners);
+		let scanner = parent + point(rng, -Vector([900; 3]), Vector([900; 3]));
+		let lo = parent.zip(scanner, i64::max) - range;
+		let hi = parent.zip(scanner, i64::min) + range;
+		let inside = |b: &&V3| (0..3).all(|i| lo[i] <= b[i] && b[i] <= hi[i]);
+		while beacons.iter().filter(inside).count() < 12 {
+			beacons.insert(point(rng, lo, hi));
+		}
+		scanners.push(scanner);
+	}
+	for &scanner in &scanners {
+		for _ in 0..rng.range(0..=10) {
+			beacons.insert(point(rng, scanner - range, scanner + range));
+		}
+	}
+
+	let rotations = rotations();
+	let mut out = String::new();
+	for (i, &scanner) in scanners.iter().enumerate() {
+		crate::debug!("scanner {} is at {}", i, scanner);
+		let rotation = *rng.choose(&rotations);
+		let mut seen: Vec<V3> = beacons.iter()
+			.filter(|&&b| b.chebyshev_distance(scanner) <= 1000)
+			.map(|&b| rotation * (b - scanner))
+			.collect();
+		rng.shuffle(&mut seen);
+
+		out += &format!("--- scanner {} ---\n", i);
+		for beacon in seen {
+			out += &format!("{}\n", beacon);
+		}
+		out.push('\n');
+	}
+
+	crate::debug!("{} beacons, scanners up to {} apart", beacons.len(),
+		max_distance(&scanners).unwrap_or(0));
+	out
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+	type Input = Vec<Vec<V3>>;
+	type Answer1 = usize;
+	type Answer2 = i64;
+
+	fn parse(input: &str) -> Result<Vec<Vec<V3>>> {
+		parse(input)
+	}
+
+	fn part1(scanners: &Vec<Vec<V3>>) -> Result<usize> {
+		let (beacons, _) = locate(scanners)?;
+		Ok(beacons.len())
+	}
+
+	fn part2(scanners: &Vec<Vec<V3>>) -> Result<i64> {
+		let (_, scanners) = locate(scanners)?;
+		max_distance(&scanners).ok_or_else(|| eyre!("no scanners"))
+	}
+}
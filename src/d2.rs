@@ -0,0 +1,93 @@
+use eyre::{Result, eyre, bail};
+
+use crate::diagnostic::{self, Located};
+use crate::generate::Rng;
+use crate::solution::Solution;
+use crate::vector::{V2, Vector};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Submarine {
+	pub pos: V2,
+	pub aim: i64,
+}
+
+impl Submarine {
+	// follows the movements, with down and up changing the aim like in part 2
+	pub fn navigate(movements: &[V2]) -> Result<Submarine> {
+		movements.iter().try_fold(Submarine::default(), |acc, &Vector([x, y])| Some(Submarine {
+			pos: Vector([
+				acc.pos[0].checked_add(x)?,
+				acc.pos[1].checked_add(x.checked_mul(acc.aim)?)?,
+			]),
+			aim: acc.aim.checked_add(y)?,
+		})).ok_or_else(|| eyre!("the submarine goes further than an i64 can count"))
+	}
+}
+
+// the position, with down and up moving the submarine like in part 1
+pub fn position(movements: &[V2]) -> Result<V2> {
+	movements.iter().try_fold(V2::ZERO, |acc, &Vector([x, y])| {
+		Some(Vector([acc[0].checked_add(x)?, acc[1].checked_add(y)?]))
+	}).ok_or_else(|| eyre!("the submarine goes further than an i64 can count"))
+}
+
+fn product(Vector([x, y]): V2) -> Result<i64> {
+	x.checked_mul(y).ok_or_else(|| eyre!("the answer doesn't fit in an i64"))
+}
+
+pub fn from_movement(s: &str) -> Result<V2> {
+	let mut words = s.split_ascii_whitespace();
+	let direction = match words.next() {
+		Some("forward") => Vector([1, 0]),
+		Some("up") => Vector([0, -1]),
+		Some("down") => Vector([0, 1]),
+		Some(word) => bail!(Located::new(s, word, "bad direction")),
+		None => bail!(Located::new(s, s, "missing direction")),
+	};
+
+	let m = words.next()
+		.ok_or_else(|| Located::new(s, &s[s.len()..], "missing magnitude"))?;
+	let n: i64 = diagnostic::locate(s, m, m.parse())?;
+	// which way to go is up to the direction. this also keeps i64::MIN from
+	// overflowing when it's negated for going up.
+	if n < 0 {
+		bail!(Located::new(s, m, "negative magnitude"));
+	}
+
+	Ok(direction * n)
+}
+
+pub fn parse(input: &str) -> Result<Vec<V2>> {
+	diagnostic::lines(input)
+		.map(|line| line.wrap(line.text, from_movement(line.text)))
+		.collect()
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let mut out = String::new();
+	for _ in 0..size {
+		let direction = rng.choose(&["forward", "forward", "down", "down", "up"]);
+		out += &format!("{} {}\n", direction, rng.range(1..=9));
+	}
+	out
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+	type Input = Vec<V2>;
+	type Answer1 = i64;
+	type Answer2 = i64;
+
+	fn parse(input: &str) -> Result<Vec<V2>> {
+		parse(input)
+	}
+
+	fn part1(movements: &Vec<V2>) -> Result<i64> {
+		product(position(movements)?)
+	}
+
+	fn part2(movements: &Vec<V2>) -> Result<i64> {
+		product(Submarine::navigate(movements)?.pos)
+	}
+}
@@ -0,0 +1,323 @@
+use std::fmt;
+use std::default::Default;
+
+use eyre::{Result, eyre, bail};
+
+use crate::diagnostic::{self, Located};
+use crate::generate::Rng;
+use crate::solution::Solution;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Var {
+	index: i32,
+}
+
+impl fmt::Display for Var {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", b"wxyz"[self.index as usize] as char)
+	}
+}
+
+#[derive(Debug)]
+pub enum Val {
+	Var(Var),
+	Lit(i64),
+}
+
+impl fmt::Display for Val {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Val::Var(v) => write!(f, "{v}"),
+			Val::Lit(v) => write!(f, "{v}"),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub enum Ins {
+	Inp(Var),
+	Add(Var, Val),
+	Mul(Var, Val),
+	Div(Var, Val),
+	Mod(Var, Val),
+	Eql(Var, Val),
+}
+
+#[derive(Debug, Default)]
+pub struct State {
+	pub vars: [i64; 4],
+}
+
+impl State {
+	pub fn apply<'a, I, J>(&mut self, instrs: I, input: J) -> Result<i64>
+			where
+				I: IntoIterator<Item = &'a Ins>,
+				J: IntoIterator<Item = i64> {
+		let mut input = input.into_iter();
+		for ins in instrs {
+			match ins {
+				Ins::Inp(var) => {
+					let i = input.next().ok_or_else(|| eyre!("unexpected eof"))?;
+					*self.var_mut(var) = i;
+				}
+				Ins::Add(var, val) => self.op(ins, var, val, i64::checked_add)?,
+				Ins::Mul(var, val) => self.op(ins, var, val, i64::checked_mul)?,
+				Ins::Div(var, val) => self.op(ins, var, val, i64::checked_div)?,
+				// the alu can't take the modulo of negative numbers either
+				Ins::Mod(var, val) => self.op(ins, var, val, |a, b| match a >= 0 && b > 0 {
+					true => Some(a % b),
+					false => None,
+				})?,
+				Ins::Eql(var, val) => {
+					let rhs = self.val(val);
+					let lhs = self.var_mut(var);
+					*lhs = if *lhs == rhs { 1 } else { 0 };
+				}
+			}
+		}
+
+		Ok(*self.var(&"z".parse()?))
+	}
+
+	// overflows and dividing by zero crash the alu
+	fn op(&mut self, ins: &Ins, var: &Var, val: &Val, f: fn(i64, i64) -> Option<i64>)
+			-> Result<()> {
+		let (a, b) = (*self.var(var), self.val(val));
+		*self.var_mut(var) = f(a, b)
+			.ok_or_else(|| eyre!("{:?} crashes the alu with {} = {} and {}", ins, var, a, b))?;
+		Ok(())
+	}
+
+	pub fn var_mut(&mut self, var: &Var) -> &mut i64 {
+		&mut self.vars[var.index as usize]
+	}
+
+	pub fn var(&self, var: &Var) -> &i64 {
+		&self.vars[var.index as usize]
+	}
+
+	pub fn val(&self, val: &Val) -> i64 {
+		match val {
+			Val::Lit(v) => *v,
+			Val::Var(v) => *self.var(v),
+		}
+	}
+}
+
+impl std::str::FromStr for Var {
+	type Err = eyre::Report;
+	fn from_str(s: &str) -> Result<Var> {
+		let index = match s {
+			"w" => 0,
+			"x" => 1,
+			"y" => 2,
+			"z" => 3,
+			_ => bail!("bad var: {}", s),
+		};
+
+		Ok(Var { index })
+	}
+}
+
+impl std::str::FromStr for Val {
+	type Err = eyre::Report;
+	fn from_str(s: &str) -> Result<Val> {
+		if s.is_empty() {
+			bail!("empty val");
+		}
+
+		if s.as_bytes()[0].is_ascii_alphabetic() {
+			Ok(Val::Var(s.parse()?))
+		} else {
+			Ok(Val::Lit(s.parse()?))
+		}
+	}
+}
+
+impl std::str::FromStr for Ins {
+	type Err = eyre::Report;
+	fn from_str(s: &str) -> Result<Ins> {
+		fn arg<T>(s: &str, a: &str) -> Result<T>
+				where T: std::str::FromStr<Err = eyre::Report> {
+			Ok(diagnostic::locate(s, a, a.parse())?)
+		}
+
+		let mut iter = s.split_whitespace();
+		use Ins::*;
+		let ins = match (iter.next(), iter.next(), iter.next(), iter.next()) {
+			(Some("inp"), Some(a), None, _) => Inp(arg(s, a)?),
+			(Some("add"), Some(a), Some(b), None) => Add(arg(s, a)?, arg(s, b)?),
+			(Some("mul"), Some(a), Some(b), None) => Mul(arg(s, a)?, arg(s, b)?),
+			(Some("div"), Some(a), Some(b), None) => Div(arg(s, a)?, arg(s, b)?),
+			(Some("mod"), Some(a), Some(b), None) => Mod(arg(s, a)?, arg(s, b)?),
+			(Some("eql"), Some(a), Some(b), None) => Eql(arg(s, a)?, arg(s, b)?),
+			(Some(op @ ("inp" | "add" | "mul" | "div" | "mod" | "eql")), ..) =>
+				bail!(Located::new(s, s, format!("wrong number of arguments for {}", op))),
+			(Some(op), ..) => bail!(Located::new(s, op, "bad instruction")),
+			(None, ..) => bail!(Located::new(s, s, "empty instruction")),
+		};
+
+		Ok(ins)
+	}
+}
+
+pub fn parse(input: &str) -> Result<Vec<Ins>> {
+	diagnostic::lines(input).map(|line| line.parse()).collect()
+}
+
+// every digit is handled by the same 18 instructions, differing only in
+// whether z gets divided by 26 (popping a base-26 digit off z), the offset
+// added to x before comparing against the input, and the offset added to y
+// before pushing the input onto z
+#[derive(Debug, Clone, Copy)]
+pub struct Block {
+	pub pop: bool,
+	pub check: i64,
+	pub offset: i64,
+}
+
+pub fn blocks(program: &[Ins]) -> Result<Vec<Block>> {
+	fn lit(val: &Val) -> Option<i64> {
+		match val {
+			&Val::Lit(v) => Some(v),
+			Val::Var(_) => None,
+		}
+	}
+
+	let mut blocks = Vec::new();
+	let mut rest = program;
+	while !rest.is_empty() {
+		let len = rest.iter().skip(1)
+			.position(|ins| matches!(ins, Ins::Inp(_)))
+			.map_or(rest.len(), |n| n + 1);
+		let (block, tail) = rest.split_at(len);
+		rest = tail;
+
+		let n = blocks.len();
+		let block = match (block.len(), block.get(4), block.get(5), block.get(15)) {
+			(18, Some(Ins::Div(z, div)), Some(Ins::Add(x, check)), Some(Ins::Add(y, offset)))
+					if z.index == 3 && x.index == 1 && y.index == 2 => {
+				let (div, check, offset) = match (lit(div), lit(check), lit(offset)) {
+					(Some(d), Some(c), Some(o)) => (d, c, o),
+					_ => bail!("block {} doesn't use literal parameters", n),
+				};
+				let pop = match div {
+					1 => false,
+					26 => true,
+					_ => bail!("block {} divides z by {}", n, div),
+				};
+				Block { pop, check, offset }
+			}
+			_ => bail!("block {} doesn't look like the usual digit check", n),
+		};
+
+		blocks.push(block);
+	}
+
+	Ok(blocks)
+}
+
+// pairs every popping block with the block that pushed the value it pops.
+// the popped digit has to equal the pushed digit plus the returned difference
+// for z to end up at 0.
+pub fn constraints(blocks: &[Block]) -> Result<Vec<(usize, usize, i64)>> {
+	let mut stack = Vec::new();
+	let mut pairs = Vec::new();
+
+	for (i, block) in blocks.iter().enumerate() {
+		if block.pop {
+			let (j, offset): (usize, i64) = stack.pop()
+				.ok_or_else(|| eyre!("block {} pops from an empty stack", i))?;
+			let diff = i64::checked_add(offset, block.check)
+				.ok_or_else(|| eyre!("block {} checks for an impossibly big difference", i))?;
+			pairs.push((j, i, diff));
+		} else {
+			stack.push((i, block.offset));
+		}
+	}
+
+	if !stack.is_empty() {
+		bail!("{} digits are never popped off z again", stack.len());
+	}
+
+	Ok(pairs)
+}
+
+pub fn model_number(program: &[Ins], largest: bool) -> Result<i64> {
+	let blocks = blocks(program)?;
+	let mut digits = vec![0i64; blocks.len()];
+
+	for (j, i, diff) in constraints(&blocks)? {
+		if !(-8..=8).contains(&diff) {
+			bail!("digits {} and {} can't differ by {}", j, i, diff);
+		}
+		let d = match largest {
+			true => i64::min(9, 9 - diff),
+			false => i64::max(1, 1 - diff),
+		};
+		if !(1..=9).contains(&d) || !(1..=9).contains(&(d + diff)) {
+			bail!("digits {} and {} can't differ by {}", j, i, diff);
+		}
+		digits[j] = d;
+		digits[i] = d + diff;
+	}
+
+	let z = State::default().apply(program, digits.iter().copied())?;
+	if z != 0 {
+		bail!("{:?} leaves z = {}, not 0", digits, z);
+	}
+
+	digits.iter().try_fold(0i64, |n, &d| n.checked_mul(10)?.checked_add(d))
+		.ok_or_else(|| eyre!("{} digits are too many for an i64", digits.len()))
+}
+
+// a program in the shape of the real ones: one block per digit, pushing or
+// popping a base-26 digit of z in a random but balanced order, with random
+// differences between the paired digits
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let pairs = (size / 2).clamp(1, 9);
+	let mut pushes_left = pairs;
+	let mut stack = Vec::new();
+	let mut out = String::new();
+
+	while pushes_left > 0 || !stack.is_empty() {
+		let offset = rng.range(1..=16);
+		let (div, check) = match pushes_left > 0 && (stack.is_empty() || rng.chance(1, 2)) {
+			true => {
+				pushes_left -= 1;
+				stack.push(offset);
+				(1, rng.range(10..=16))
+			}
+			false => {
+				let pushed = stack.pop().unwrap_or_default();
+				(26, rng.range(-8..=8) - pushed)
+			}
+		};
+
+		out += &format!("inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\n\
+			eql x w\neql x 0\nmul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\n\
+			mul y 0\nadd y w\nadd y {}\nmul y x\nadd z y\n", div, check, offset);
+	}
+
+	out
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+	type Input = Vec<Ins>;
+	type Answer1 = i64;
+	type Answer2 = i64;
+
+	fn parse(input: &str) -> Result<Vec<Ins>> {
+		parse(input)
+	}
+
+	fn part1(program: &Vec<Ins>) -> Result<i64> {
+		model_number(program, true)
+	}
+
+	fn part2(program: &Vec<Ins>) -> Result<i64> {
+		model_number(program, false)
+	}
+}
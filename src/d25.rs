@@ -0,0 +1,143 @@
+use eyre::{Result, bail};
+
+use crate::cancel;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::image::{Image, Images};
+use crate::render::{Color, Render, Screen};
+use crate::solution::{Part, Solution};
+
+pub fn parse(input: &str) -> Result<Grid<char>> {
+	let grid = Grid::parse_with(input, |c| match c {
+		'.' | '>' | 'v' => Some(c),
+		_ => None,
+	})?;
+
+	Ok(grid.toroidal())
+}
+
+// moves every sea cucumber of the herd that has room in front of it. they all
+// look before any of them move.
+fn step_herd(grid: &mut Grid<char>, can_move: &mut Grid<bool>, herd: char,
+		direction: (isize, isize)) -> bool {
+	for (pos, &c) in grid.iter() {
+		can_move[pos] = c == herd
+			&& grid.neighbor(pos, direction).is_some_and(|next| grid[next] == '.');
+	}
+
+	let mut any_moved = false;
+	for pos in can_move.positions() {
+		if can_move[pos] {
+			if let Some(next) = grid.neighbor(pos, direction) {
+				any_moved = true;
+				grid[pos] = '.';
+				grid[next] = herd;
+			}
+		}
+	}
+
+	any_moved
+}
+
+// calls back with the seafloor before every step
+pub fn steps_until_stuck(grid: &mut Grid<char>,
+		mut on_step: impl FnMut(&Grid<char>) -> Result<()>) -> Result<u32> {
+	if grid.is_empty() {
+		return Ok(0);
+	}
+
+	let mut steps: u32 = 0;
+	let mut can_move = Grid::new(grid.width(), grid.height(), false);
+	// the herds can go round in circles forever on some maps. to notice, every
+	// state is compared against one from a while ago, a power of two steps in.
+	let mut snapshot = (grid.clone(), 0);
+
+	loop {
+		crate::trace!("step {}:\n{}", steps, grid);
+		cancel::check(|| format!("still moving after {} steps", steps))?;
+		on_step(grid)?;
+
+		let east = step_herd(grid, &mut can_move, '>', (1, 0));
+		let south = step_herd(grid, &mut can_move, 'v', (0, 1));
+		steps += 1;
+
+		if !east && !south {
+			break;
+		}
+
+		if *grid == snapshot.0 {
+			bail!("the sea cucumbers never stop, they're back where they were after {} steps",
+				snapshot.1);
+		}
+		if steps.is_power_of_two() {
+			snapshot = (grid.clone(), steps);
+		}
+	}
+
+	Ok(steps)
+}
+
+struct Seafloor<'a>(&'a Grid<char>);
+
+impl Render for Seafloor<'_> {
+	fn size(&self) -> (usize, usize) {
+		(self.0.width(), self.0.height())
+	}
+
+	fn cell(&self, x: usize, y: usize) -> (char, Option<Color>) {
+		let c = self.0[(x, y)];
+		(c, match c {
+			'>' => Some(Color::Yellow),
+			'v' => Some(Color::Cyan),
+			_ => Some(Color::Gray),
+		})
+	}
+}
+
+// every generation until the herds are stuck
+pub fn render(input: &str, _: Part, screen: &mut Screen) -> Result<()> {
+	let mut grid = parse(input)?;
+	steps_until_stuck(&mut grid, |grid| screen.frame(&Seafloor(grid)))?;
+	Ok(())
+}
+
+// one image per generation
+pub fn export(input: &str, _: Part, images: &mut Images) -> Result<()> {
+	let mut grid = parse(input)?;
+	steps_until_stuck(&mut grid, |grid| images.save(&Image::from_render(&Seafloor(grid))))?;
+	Ok(())
+}
+
+// a square seafloor with about a third of it free
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let mut out = String::new();
+	for _ in 0..size {
+		for _ in 0..size {
+			out.push(*rng.choose(&['.', '>', 'v']));
+		}
+		out.push('\n');
+	}
+	out
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+	type Input = Grid<char>;
+	type Answer1 = u32;
+	type Answer2 = u32;
+
+	const PARTS: &'static [Part] = &[Part::One];
+
+	fn parse(input: &str) -> Result<Grid<char>> {
+		parse(input)
+	}
+
+	fn part1(grid: &Grid<char>) -> Result<u32> {
+		steps_until_stuck(&mut grid.clone(), |_| Ok(()))
+	}
+
+	fn part2(_: &Grid<char>) -> Result<u32> {
+		bail!("day 25 only has one part")
+	}
+}
@@ -0,0 +1,188 @@
+// --- Day 3: Binary Diagnostic ---
+// 
+// The submarine has been making some odd creaking noises, so you ask it to
+// produce a diagnostic report just in case.
+// 
+// The diagnostic report (your puzzle input) consists of a list of binary
+// numbers which, when decoded properly, can tell you many useful things about
+// the conditions of the submarine. The first parameter to check is the power
+// consumption.
+// 
+// You need to use the binary numbers in the diagnostic report to generate two
+// new binary numbers (called the gamma rate and the epsilon rate). The power
+// consumption can then be found by multiplying the gamma rate by the epsilon
+// rate.
+// 
+// Each bit in the gamma rate can be determined by finding the most common bit
+// in the corresponding position of all numbers in the diagnostic report. For
+// example, given the following diagnostic report:
+// 
+// 00100
+// 11110
+// 10110
+// 10111
+// 10101
+// 01111
+// 00111
+// 11100
+// 10000
+// 11001
+// 00010
+// 01010
+// 
+// Considering only the first bit of each number, there are five 0 bits and
+// seven 1 bits. Since the most common bit is 1, the first bit of the gamma rate
+// is 1.
+// 
+// The most common second bit of the numbers in the diagnostic report is 0, so
+// the second bit of the gamma rate is 0.
+// 
+// The most common value of the third, fourth, and fifth bits are 1, 1, and 0,
+// respectively, and so the final three bits of the gamma rate are 110.
+// 
+// So, the gamma rate is the binary number 10110, or 22 in decimal.
+// 
+// The epsilon rate is calculated in a similar way; rather than use the most
+// common bit, the least common bit from each position is used. So, the epsilon
+// rate is 01001, or 9 in decimal. Multiplying the gamma rate (22) by the
+// epsilon rate (9) produces the power consumption, 198.
+// 
+// Use the binary numbers in your diagnostic report to calculate the gamma rate
+// and epsilon rate, then multiply them together. What is the power consumption
+// of the submarine? (Be sure to represent your answer in decimal, not binary.)
+
+use std::collections::HashSet;
+
+use eyre::{Result, eyre, bail};
+
+use crate::diagnostic;
+use crate::generate::Rng;
+use crate::solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<String>> {
+    let mut lines = Vec::new();
+    for line in diagnostic::lines(input) {
+        if let Some(i) = line.text.bytes().position(|b| b != b'0' && b != b'1') {
+            bail!(line.error_at(i..i+1, "expected a binary digit"));
+        }
+        if line.text.len() > 32 {
+            bail!(line.error(line.text, "more than 32 digits"));
+        }
+        if let Some(first) = lines.first().map(String::len) {
+            if line.text.len() != first {
+                bail!(line.error(line.text,
+                    format!("expected {} digits like the first line, got {}",
+                        first, line.text.len())));
+            }
+        }
+        lines.push(line.text.to_string());
+    }
+
+    if lines.is_empty() {
+        bail!("input empty");
+    }
+
+    Ok(lines)
+}
+
+pub fn gamma_epsilon(lines: &[String]) -> (u32, u32) {
+    let mut ones = Vec::new();
+    for line in lines {
+        ones.resize(usize::max(line.len(), ones.len()), 0);
+        for (digit, counter) in line.bytes().zip(&mut ones) {
+            if digit == b'1' {
+                *counter += 1;
+            }
+        }
+    }
+    crate::debug!("ones per column: {:?}", ones);
+    let mut gamma = 0u32;
+    let mut mask = 0;
+    for &counter in &ones {
+        crate::trace!("counter = {}", counter);
+        let digit = if counter > lines.len() / 2 { 1 } else { 0 };
+        gamma = gamma << 1 | digit;
+        mask = mask << 1 | 1;
+    }
+    let epsilon = !gamma & mask;
+
+    (gamma, epsilon)
+}
+
+pub fn get_rating(mut lines: Vec<String>, want_most_common: bool)
+        -> Result<Option<String>> {
+    for i in 0..lines.first().map_or(0, String::len) {
+        let mut lines_one = Vec::new();
+        let mut lines_zero = Vec::new();
+
+        for line in lines {
+            match line.as_bytes().get(i) {
+                Some(b'1') => &mut lines_one,
+                Some(b'0') => &mut lines_zero,
+                _ => bail!("bad digit in {:?}", line),
+            }.push(line);
+        }
+
+        // if every line agrees on this bit, there's nothing to filter on
+        lines = if lines_zero.is_empty() {
+            lines_one
+        } else if lines_one.is_empty() {
+            lines_zero
+        } else if want_most_common == (lines_one.len() >= lines_zero.len()) {
+            lines_one
+        } else {
+            lines_zero
+        };
+
+        if lines.len() == 1 {
+            return Ok(lines.pop());
+        }
+    }
+
+    Ok(None)
+}
+
+// distinct numbers, like the real input. the width grows with the size so
+// there are always enough of them.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = usize::max(12, (usize::BITS - size.leading_zeros()) as usize + 1);
+    let mut seen = HashSet::new();
+    let mut numbers = Vec::new();
+    while numbers.len() < size {
+        let n = rng.below(1 << width);
+        if seen.insert(n) {
+            numbers.push(n);
+        }
+    }
+
+    numbers.iter().map(|n| format!("{:0width$b}\n", n, width = width)).collect()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        parse(input)
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<u64> {
+        let (gamma, epsilon) = gamma_epsilon(lines);
+        Ok(gamma as u64 * epsilon as u64)
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<u64> {
+        let oxygen_gen_rating = get_rating(lines.clone(), true)?
+            .ok_or_else(|| eyre!("no oxygen gen rating"))?;
+        let co2_scrubber_rating = get_rating(lines.clone(), false)?
+            .ok_or_else(|| eyre!("no co2 scrubber rating"))?;
+
+        let oxygen_gen_rating = u32::from_str_radix(&oxygen_gen_rating, 2)?;
+        let co2_scrubber_rating = u32::from_str_radix(&co2_scrubber_rating, 2)?;
+
+        Ok(oxygen_gen_rating as u64 * co2_scrubber_rating as u64)
+    }
+}
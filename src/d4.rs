@@ -0,0 +1,167 @@
+use eyre::{Result, eyre, bail};
+
+use crate::diagnostic;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+pub type Square = Grid<Option<i32>>;
+
+#[derive(Debug, Clone)]
+pub struct Bingo {
+	pub drawings: Vec<i32>,
+	pub squares: Vec<Square>,
+}
+
+pub fn parse(input: &str) -> Result<Bingo> {
+	let mut lines = diagnostic::lines(input).peekable();
+	let drawings_line = lines.next()
+		.ok_or_else(|| eyre!("drawn numbers missing"))?;
+	let drawings: Vec<i32> = drawings_line.text.split(',')
+		.map(|s| drawings_line.wrap(s, s.parse()))
+		.collect::<Result<Vec<i32>>>()?;
+
+	match lines.next() {
+		None => bail!("unexpected eof"),
+		Some(line) if !line.text.is_empty() =>
+			bail!(line.error(line.text, "unexpected non-empty line")),
+		Some(_) => (),
+	}
+
+	let mut squares = Vec::new();
+	let mut dim = None;
+	loop {
+		// however many blank lines there are between squares
+		while lines.next_if(|line| line.text.is_empty()).is_some() {}
+
+		let mut square = Vec::new();
+		let mut last = None;
+		for line in (&mut lines).take_while(|line| !line.text.is_empty()) {
+			let row = line.text.split_ascii_whitespace()
+				.map(|s| line.wrap(s, s.parse().map(Some)))
+				.collect::<Result<Vec<_>>>()?;
+			let dim = *dim.get_or_insert(row.len());
+			if row.len() != dim {
+				bail!(line.error(line.text,
+					format!("expected {} numbers like the first row, got {}", dim, row.len())));
+			}
+			if square.len() == dim {
+				bail!(line.error(line.text, format!("square has more than {} rows", dim)));
+			}
+			square.push(row);
+			last = Some(line);
+		}
+
+		match last {
+			None => break,
+			Some(line) if Some(square.len()) != dim =>
+				bail!(line.error(line.text, format!("square ends after {} rows, expected {}",
+					square.len(), dim.unwrap_or_default()))),
+			Some(_) => squares.push(Grid::from_rows(square)?),
+		}
+	}
+
+	if squares.is_empty() {
+		bail!("no squares");
+	}
+
+	Ok(Bingo { drawings, squares })
+}
+
+pub fn get_first_winning_score<I>(squares: &mut [Square], drawings: I)
+			-> Result<Option<i32>>
+		where I: Iterator<Item=i32> {
+	for number in drawings {
+		for square in &mut *squares {
+			mark_number_on_square(square, number);
+
+			if square_won(square) {
+				return Ok(Some(square_score(square, number)?));
+			}
+		}
+	}
+
+	Ok(None)
+}
+
+pub fn get_last_winning_score<I>(squares: &mut [Square], drawings: I)
+			-> Result<Option<i32>>
+		where I: Iterator<Item=i32> {
+	let mut score = None;
+	for number in drawings {
+		for square in &mut *squares {
+			if square_won(square) {
+				continue;
+			}
+
+			mark_number_on_square(square, number);
+
+			if square_won(square) {
+				score = Some(square_score(square, number)?);
+			}
+		}
+	}
+
+	Ok(score)
+}
+
+fn square_won(square: &Square) -> bool {
+	square.rows().any(|row| row.iter().all(|cell| cell.is_none()))
+		|| (0..square.width()).any(|x| square.column(x).all(|cell| cell.is_none()))
+}
+
+fn square_score(square: &Square, number: i32) -> Result<i32> {
+	square.cells().flatten().try_fold(0i32, |sum, &n| sum.checked_add(n))
+		.and_then(|sum| sum.checked_mul(number))
+		.ok_or_else(|| eyre!("score overflows"))
+}
+
+fn mark_number_on_square(square: &mut Square, number: i32) {
+	for cell in square.cells_mut() {
+		if cell == &Some(number) {
+			*cell = None;
+		}
+	}
+}
+
+// every number gets drawn eventually, so every square wins eventually
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let mut numbers: Vec<i64> = (0..100).collect();
+	rng.shuffle(&mut numbers);
+	let drawings: Vec<_> = numbers.iter().map(i64::to_string).collect();
+	let mut out = format!("{}\n", drawings.join(","));
+
+	for _ in 0..size {
+		rng.shuffle(&mut numbers);
+		out.push('\n');
+		for row in numbers[..25].chunks(5) {
+			let row: Vec<_> = row.iter().map(|n| format!("{:2}", n)).collect();
+			out += &format!("{}\n", row.join(" "));
+		}
+	}
+	out
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+	type Input = Bingo;
+	type Answer1 = i32;
+	type Answer2 = i32;
+
+	fn parse(input: &str) -> Result<Bingo> {
+		parse(input)
+	}
+
+	fn part1(bingo: &Bingo) -> Result<i32> {
+		let mut squares = bingo.squares.clone();
+		get_first_winning_score(&mut squares, bingo.drawings.iter().copied())?
+			.ok_or_else(|| eyre!("nobody won"))
+	}
+
+	fn part2(bingo: &Bingo) -> Result<i32> {
+		let mut squares = bingo.squares.clone();
+		get_last_winning_score(&mut squares, bingo.drawings.iter().copied())?
+			.ok_or_else(|| eyre!("nobody won"))
+	}
+}
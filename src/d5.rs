@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use eyre::{Result, bail};
+
+use crate::diagnostic;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::image::{Image, Images};
+use crate::render::{Color, Render, Screen};
+use crate::solution::{Part, Solution};
+use crate::vector::{V2, Vector};
+
+// every point a line passes through gets counted, so that's what takes time
+// and memory, however far apart the lines are. real inputs cover a few
+// hundred thousand.
+pub const MAX_POINTS: i64 = 10_000_000;
+
+pub fn parse(input: &str) -> Result<Vec<(V2, V2)>> {
+	let mut covered = 0i64;
+	diagnostic::lines(input).map(|line| {
+		let point = |s: &str| -> Result<V2> {
+			let p: V2 = line.wrap(s, s.parse())?;
+			if p.0.iter().any(|&c| c < 0) {
+				bail!(line.error(s, "coords can't be negative"));
+			}
+			Ok(p)
+		};
+
+		let mut points = line.text.split(" -> ");
+		let (p, q) = match (points.next(), points.next(), points.next()) {
+			(Some(p), Some(q), None) => (point(p)?, point(q)?),
+			_ => bail!(line.error(line.text, "expected two points")),
+		};
+
+		// both points are at least 0, so the distance can't overflow
+		covered = covered.saturating_add(p.chebyshev_distance(q).saturating_add(1));
+		if covered > MAX_POINTS {
+			bail!(line.error(line.text,
+				format!("the lines so far cover more than {} points", MAX_POINTS)));
+		}
+		Ok((p, q))
+	}).collect()
+}
+
+// lines are horizontal, vertical or, if we're counting those, at 45 degrees
+pub fn draw_line(counts: &mut HashMap<V2, usize>, p: V2, q: V2, diagonals: bool) {
+	let Vector([dx, dy]) = q - p;
+	if dx != 0 && dy != 0 && !(diagonals && dx.abs() == dy.abs()) {
+		return;
+	}
+
+	let step = (q - p).signum();
+	for i in 0..=p.chebyshev_distance(q) {
+		let point = p + step * i;
+		crate::trace!("{} -> {} step {}: {}", p, q, i, point);
+		*counts.entry(point).or_insert(0) += 1;
+	}
+}
+
+pub fn count_intersections(counts: &HashMap<V2, usize>, min: usize) -> usize {
+	counts.values().filter(|&&c| c >= min).count()
+}
+
+// how many lines pass through each point, for the points any do
+pub fn overlaps(lines: &[(V2, V2)], diagonals: bool) -> HashMap<V2, usize> {
+	let mut counts = HashMap::new();
+
+	for &(p, q) in lines {
+		draw_line(&mut counts, p, q, diagonals);
+		crate::debug!("{} -> {}", p, q);
+	}
+
+	counts
+}
+
+pub fn count_overlaps(lines: &[(V2, V2)], diagonals: bool) -> usize {
+	count_intersections(&overlaps(lines, diagonals), 2)
+}
+
+// the map from the origin to the furthest point, like in the puzzle text
+struct Overlaps {
+	counts: HashMap<V2, usize>,
+	size: (usize, usize),
+}
+
+impl Overlaps {
+	fn new(lines: &[(V2, V2)], diagonals: bool) -> Overlaps {
+		let points = lines.iter().flat_map(|&(p, q)| [p, q]);
+		let size = |i: usize| points.clone().map(|p| p[i] as usize + 1).max().unwrap_or(0);
+		Overlaps { size: (size(0), size(1)), counts: overlaps(lines, diagonals) }
+	}
+
+	fn count(&self, x: usize, y: usize) -> usize {
+		self.counts.get(&Vector([x as i64, y as i64])).copied().unwrap_or(0)
+	}
+}
+
+impl Render for Overlaps {
+	fn size(&self) -> (usize, usize) {
+		self.size
+	}
+
+	fn cell(&self, x: usize, y: usize) -> (char, Option<Color>) {
+		match self.count(x, y) {
+			0 => ('.', Some(Color::Gray)),
+			1 => ('1', None),
+			n => (char::from_digit(n as u32, 10).unwrap_or('+'), Some(Color::Red)),
+		}
+	}
+}
+
+// the overlap counts like in the puzzle text, diagonals only for part 2
+pub fn render(input: &str, part: Part, screen: &mut Screen) -> Result<()> {
+	let lines = parse(input)?;
+	screen.frame(&Overlaps::new(&lines, part == Part::Two))
+}
+
+// a heatmap of the overlap counts, brighter where more lines cross. unlike
+// counting, this needs a pixel for every point up to the furthest one.
+pub fn export(input: &str, part: Part, images: &mut Images) -> Result<()> {
+	let overlaps = Overlaps::new(&parse(input)?, part == Part::Two);
+	let (width, height) = overlaps.size;
+	if width.checked_mul(height).is_none_or(|n| n > MAX_POINTS as usize) {
+		bail!("a {}x{} image would be too big", width, height);
+	}
+	let max = overlaps.counts.values().copied().max().unwrap_or(0).max(1);
+	images.save(&Image::Gray(Grid::from_fn(width, height,
+		|x, y| (overlaps.count(x, y) * 255 / max) as u8)))
+}
+
+// horizontal, vertical and diagonal lines, with coordinates up to twice the
+// number of lines, and short enough that they don't cover too many points
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let max = i64::try_from(size).unwrap_or(i64::MAX).saturating_mul(2).max(10);
+	let longest = MAX_POINTS / 2 / max;
+	let mut out = String::new();
+	for _ in 0..size {
+		let p = Vector([rng.range(0..=max), rng.range(0..=max)]);
+		let direction = *rng.choose(&[
+			Vector([1, 0]), Vector([-1, 0]), Vector([0, 1]), Vector([0, -1]),
+			Vector([1, 1]), Vector([1, -1]), Vector([-1, 1]), Vector([-1, -1]),
+		]);
+		// as far as it can go without leaving the area
+		let room = (0..2).map(|i| match direction[i] {
+			1 => max - p[i],
+			-1 => p[i],
+			_ => max,
+		}).min().unwrap_or(0);
+		let q: V2 = p + direction * rng.range(0..=room.min(longest));
+		out += &format!("{} -> {}\n", p, q);
+	}
+	out
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+	type Input = Vec<(V2, V2)>;
+	type Answer1 = usize;
+	type Answer2 = usize;
+
+	fn parse(input: &str) -> Result<Vec<(V2, V2)>> {
+		parse(input)
+	}
+
+	fn part1(lines: &Vec<(V2, V2)>) -> Result<usize> {
+		Ok(count_overlaps(lines, false))
+	}
+
+	fn part2(lines: &Vec<(V2, V2)>) -> Result<usize> {
+		Ok(count_overlaps(lines, true))
+	}
+}
@@ -0,0 +1,66 @@
+use eyre::{Result, eyre, bail};
+
+use crate::solution::{self, Part, Parsed, Solution};
+use crate::{d1, d2, d3, d4, d5, d15, d16, d17, d18, d19, d24, d25};
+
+// one way of solving a day. days with more than one implementation list the
+// default one first.
+pub struct Day {
+	pub day: u32,
+	pub name: &'static str,
+	pub parts: &'static [Part],
+	pub parse: fn(&str) -> Result<Parsed>,
+	pub solve: fn(&Parsed, Part) -> Result<String>,
+}
+
+impl Day {
+	const fn new<S: Solution>(day: u32, name: &'static str) -> Day {
+		Day {
+			day,
+			name,
+			parts: S::PARTS,
+			parse: solution::parse::<S>,
+			solve: solution::solve::<S>,
+		}
+	}
+
+	pub fn run(&self, input: &str, part: Part) -> Result<String> {
+		(self.solve)(&(self.parse)(input)?, part)
+	}
+}
+
+pub static DAYS: &[Day] = &[
+	Day::new::<d1::Day1>(1, "default"),
+	Day::new::<d2::Day2>(2, "default"),
+	Day::new::<d3::Day3>(3, "default"),
+	Day::new::<d4::Day4>(4, "default"),
+	Day::new::<d5::Day5>(5, "default"),
+	Day::new::<d15::Day15>(15, "default"),
+	Day::new::<d16::nibble::Day16>(16, "nibble"),
+	Day::new::<d16::bitvec::Day16>(16, "bitvec"),
+	Day::new::<d17::Day17>(17, "default"),
+	Day::new::<d18::tree::Day18>(18, "tree"),
+	Day::new::<d18::string::Day18>(18, "string"),
+	Day::new::<d19::Day19>(19, "default"),
+	Day::new::<d24::Day24>(24, "default"),
+	Day::new::<d25::Day25>(25, "default"),
+];
+
+// finds the named implementation for a day, or the default one if no name is
+// given
+pub fn find(day: u32, name: Option<&str>) -> Result<&'static Day> {
+	let mut candidates = DAYS.iter().filter(|d| d.day == day).peekable();
+	if candidates.peek().is_none() {
+		bail!("day {} isn't implemented", day);
+	}
+
+	match name {
+		None => Ok(candidates.next().unwrap()),
+		Some(name) => {
+			let names: Vec<_> = candidates.clone().map(|d| d.name).collect();
+			candidates.find(|d| d.name == name)
+				.ok_or_else(|| eyre!("day {} has no implementation {:?}, only {}",
+					day, name, names.join(", ")))
+		}
+	}
+}
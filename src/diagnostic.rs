@@ -0,0 +1,115 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use eyre::Report;
+
+// a parse error pointing at the offending part of a line of the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+	pub line: usize,
+	pub columns: Range<usize>,
+	pub text: String,
+	pub message: String,
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		writeln!(f, "line {}, column {}: {}", self.line, self.columns.start + 1, self.message)?;
+
+		let gutter = self.line.to_string().len();
+		writeln!(f, "{} | {}", self.line, self.text)?;
+		write!(f, "{:gutter$} | {:start$}{}", "", "",
+			"^".repeat(usize::max(1, self.columns.len())),
+			start = self.columns.start)
+	}
+}
+
+impl Error for ParseError {}
+
+// an error about part of a string, from a parser that doesn't know which line
+// it's looking at. Line::wrap turns it into a ParseError for the whole line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Located {
+	pub columns: Range<usize>,
+	pub message: String,
+}
+
+impl fmt::Display for Located {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "column {}: {}", self.columns.start + 1, self.message)
+	}
+}
+
+impl Error for Located {}
+
+impl Located {
+	pub fn new(whole: &str, part: &str, message: impl fmt::Display) -> Located {
+		Located { columns: span(whole, part), message: message.to_string() }
+	}
+}
+
+// where `part`, which should be a slice of `whole`, sits in it. anything else
+// is taken to mean all of `whole`.
+pub fn span(whole: &str, part: &str) -> Range<usize> {
+	let start = (part.as_ptr() as usize).wrapping_sub(whole.as_ptr() as usize);
+	match start <= whole.len() && part.len() <= whole.len() - start {
+		true => start..start + part.len(),
+		false => 0..whole.len(),
+	}
+}
+
+// attaches the location of `part` within `whole` to the error from parsing
+// it, keeping any more precise location the error already has
+pub fn locate<T, E: Into<Report>>(whole: &str, part: &str, result: Result<T, E>)
+		-> Result<T, Located> {
+	result.map_err(|e| {
+		let e = e.into();
+		let columns = span(whole, part);
+		match e.downcast_ref::<Located>() {
+			Some(inner) => Located {
+				columns: columns.start + inner.columns.start..columns.start + inner.columns.end,
+				message: inner.message.clone(),
+			},
+			None => Located { columns, message: format!("{:#}", e) },
+		}
+	})
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+	pub number: usize,
+	pub text: &'a str,
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+	input.lines().enumerate().map(|(i, text)| Line { number: i + 1, text })
+}
+
+impl Line<'_> {
+	pub fn error_at(&self, columns: Range<usize>, message: impl fmt::Display)
+			-> ParseError {
+		ParseError {
+			line: self.number,
+			columns,
+			text: self.text.to_string(),
+			message: message.to_string(),
+		}
+	}
+
+	pub fn error(&self, part: &str, message: impl fmt::Display) -> ParseError {
+		self.error_at(span(self.text, part), message)
+	}
+
+	pub fn wrap<T, E: Into<Report>>(&self, part: &str, result: Result<T, E>)
+			-> eyre::Result<T> {
+		locate(self.text, part, result)
+			.map_err(|l| self.error_at(l.columns, l.message).into())
+	}
+
+	pub fn parse<T>(&self) -> eyre::Result<T>
+			where T: FromStr, T::Err: Into<Report> {
+		self.wrap(self.text, self.text.parse())
+	}
+}
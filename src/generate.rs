@@ -0,0 +1,89 @@
+use std::ops::RangeInclusive;
+
+use eyre::{Result, eyre};
+
+use crate::{d1, d2, d3, d4, d5, d15, d16, d17, d18, d19, d24, d25};
+
+// splitmix64. not remotely cryptographic, but tiny, fast, and the same on
+// every platform, so a seed always gives the same input.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+	pub fn new(seed: u64) -> Rng {
+		Rng(seed)
+	}
+
+	pub fn next_u64(&mut self) -> u64 {
+		self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+		let mut z = self.0;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+		z ^ (z >> 31)
+	}
+
+	// uniform in 0..n, give or take a bias too small to matter here
+	pub fn below(&mut self, n: u64) -> u64 {
+		assert!(n > 0, "empty range");
+		((self.next_u64() as u128 * n as u128) >> 64) as u64
+	}
+
+	pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+		let (lo, hi) = range.into_inner();
+		assert!(lo <= hi, "empty range");
+		lo.wrapping_add(self.below(hi.abs_diff(lo) + 1) as i64)
+	}
+
+	pub fn index(&mut self, len: usize) -> usize {
+		self.below(len as u64) as usize
+	}
+
+	pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+		self.below(denominator) < numerator
+	}
+
+	pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+		&items[self.index(items.len())]
+	}
+
+	pub fn shuffle<T>(&mut self, items: &mut [T]) {
+		for i in (1..items.len()).rev() {
+			items.swap(i, self.index(i + 1));
+		}
+	}
+}
+
+// writes a random input for one day. what the size means is up to the day,
+// but bigger is always harder; the default is about as big as the real
+// puzzle input.
+pub struct Generator {
+	pub day: u32,
+	pub default_size: usize,
+	pub generate: fn(&mut Rng, usize) -> String,
+}
+
+pub static GENERATORS: &[Generator] = &[
+	Generator { day: 1, default_size: 2000, generate: d1::generate },
+	Generator { day: 2, default_size: 1000, generate: d2::generate },
+	Generator { day: 3, default_size: 1000, generate: d3::generate },
+	Generator { day: 4, default_size: 100, generate: d4::generate },
+	Generator { day: 5, default_size: 500, generate: d5::generate },
+	Generator { day: 15, default_size: 100, generate: d15::generate },
+	Generator { day: 16, default_size: 60, generate: d16::generate },
+	Generator { day: 17, default_size: 100, generate: d17::generate },
+	Generator { day: 18, default_size: 100, generate: d18::generate },
+	Generator { day: 19, default_size: 30, generate: d19::generate },
+	Generator { day: 24, default_size: 14, generate: d24::generate },
+	Generator { day: 25, default_size: 140, generate: d25::generate },
+];
+
+pub fn find(day: u32) -> Result<&'static Generator> {
+	GENERATORS.iter().find(|g| g.day == day)
+		.ok_or_else(|| eyre!("there's no generator for day {}", day))
+}
+
+pub fn generate(day: u32, seed: u64, size: Option<usize>) -> Result<String> {
+	let generator = find(day)?;
+	let size = size.unwrap_or(generator.default_size);
+	Ok((generator.generate)(&mut Rng::new(seed), size))
+}
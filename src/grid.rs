@@ -0,0 +1,219 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use eyre::{Result, bail};
+
+use crate::diagnostic;
+
+// a rectangle of cells, stored row by row and indexed by (x, y). neighbors
+// off the edge don't exist, unless the grid is toroidal and they wrap around.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+	width: usize,
+	height: usize,
+	cells: Vec<T>,
+	toroidal: bool,
+}
+
+pub const DIRECTIONS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+pub const DIRECTIONS8: [(isize, isize); 8] =
+	[(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+impl<T> Grid<T> {
+	pub fn new(width: usize, height: usize, fill: T) -> Grid<T> where T: Clone {
+		Grid { width, height, cells: vec![fill; width * height], toroidal: false }
+	}
+
+	pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T)
+			-> Grid<T> {
+		let cells = (0..height)
+			.flat_map(|y| (0..width).map(move |x| (x, y)))
+			.map(|(x, y)| f(x, y))
+			.collect();
+		Grid { width, height, cells, toroidal: false }
+	}
+
+	pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>> {
+		let width = rows.first().map_or(0, Vec::len);
+		let height = rows.len();
+		if let Some(y) = rows.iter().position(|row| row.len() != width) {
+			bail!("row {} has {} cells, expected {}", y, rows[y].len(), width);
+		}
+
+		Ok(Grid { width, height, cells: rows.into_iter().flatten().collect(), toroidal: false })
+	}
+
+	// one cell per character of every line, with `cell` deciding what the
+	// characters mean, or that they don't mean anything
+	pub fn parse_with(input: &str, mut cell: impl FnMut(char) -> Option<T>)
+			-> Result<Grid<T>> {
+		let mut width = None;
+		let mut height = 0;
+		let mut cells = Vec::new();
+
+		for line in diagnostic::lines(input) {
+			let mut n = 0;
+			for (i, c) in line.text.char_indices() {
+				match cell(c) {
+					Some(value) => cells.push(value),
+					None => bail!(line.error_at(i..i + c.len_utf8(),
+						format!("unexpected character {:?}", c))),
+				}
+				n += 1;
+			}
+
+			let width = *width.get_or_insert(n);
+			if n != width {
+				bail!(line.error(line.text,
+					format!("expected {} cells like the first line, got {}", width, n)));
+			}
+			height += 1;
+		}
+
+		Ok(Grid { width: width.unwrap_or(0), height, cells, toroidal: false })
+	}
+
+	// makes neighbors wrap around the edges
+	pub fn toroidal(self) -> Grid<T> {
+		Grid { toroidal: true, ..self }
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.cells.is_empty()
+	}
+
+	pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+		match x < self.width && y < self.height {
+			true => self.cells.get(y * self.width + x),
+			false => None,
+		}
+	}
+
+	pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+		match x < self.width && y < self.height {
+			true => self.cells.get_mut(y * self.width + x),
+			false => None,
+		}
+	}
+
+	pub fn row(&self, y: usize) -> &[T] {
+		&self.cells[y * self.width..][..self.width]
+	}
+
+	pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+		(0..self.height).map(move |y| self.row(y))
+	}
+
+	pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+		self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+	}
+
+	pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+		let width = self.width;
+		(0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+		self.positions().zip(&self.cells)
+	}
+
+	pub fn cells(&self) -> impl Iterator<Item = &T> {
+		self.cells.iter()
+	}
+
+	pub fn cells_mut(&mut self) -> impl Iterator<Item = &mut T> {
+		self.cells.iter_mut()
+	}
+
+	pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+		Grid {
+			width: self.width,
+			height: self.height,
+			cells: self.cells.iter().map(f).collect(),
+			toroidal: self.toroidal,
+		}
+	}
+
+	// the position one step of (dx, dy) away, if there is one
+	pub fn neighbor(&self, (x, y): (usize, usize), (dx, dy): (isize, isize))
+			-> Option<(usize, usize)> {
+		if self.toroidal {
+			if self.is_empty() {
+				return None;
+			}
+			let x = (x as isize + dx).rem_euclid(self.width as isize) as usize;
+			let y = (y as isize + dy).rem_euclid(self.height as isize) as usize;
+			return Some((x, y));
+		}
+
+		let x = x.checked_add_signed(dx)?;
+		let y = y.checked_add_signed(dy)?;
+		match x < self.width && y < self.height {
+			true => Some((x, y)),
+			false => None,
+		}
+	}
+
+	pub fn neighbors4(&self, pos: (usize, usize))
+			-> impl Iterator<Item = (usize, usize)> + '_ {
+		DIRECTIONS4.iter().filter_map(move |&d| self.neighbor(pos, d))
+	}
+
+	pub fn neighbors8(&self, pos: (usize, usize))
+			-> impl Iterator<Item = (usize, usize)> + '_ {
+		DIRECTIONS8.iter().filter_map(move |&d| self.neighbor(pos, d))
+	}
+}
+
+impl Grid<u8> {
+	pub fn parse_digits(input: &str) -> Result<Grid<u8>> {
+		Grid::parse_with(input, |c| c.to_digit(10).map(|d| d as u8))
+	}
+}
+
+impl Grid<char> {
+	pub fn parse_chars(input: &str) -> Result<Grid<char>> {
+		Grid::parse_with(input, Some)
+	}
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+	type Output = T;
+
+	fn index(&self, (x, y): (usize, usize)) -> &T {
+		assert!(x < self.width && y < self.height,
+			"({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+		&self.cells[y * self.width + x]
+	}
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+	fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+		assert!(x < self.width && y < self.height,
+			"({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+		&mut self.cells[y * self.width + x]
+	}
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for (y, row) in self.rows().enumerate() {
+			if y > 0 {
+				writeln!(f)?;
+			}
+			for cell in row {
+				write!(f, "{}", cell)?;
+			}
+		}
+
+		Ok(())
+	}
+}
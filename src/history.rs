@@ -0,0 +1,144 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use eyre::{Result, WrapErr, eyre};
+
+// benchmark results saved over time, one csv line per stage of every
+// benchmark, so later runs can be compared against them
+
+const HEADER: &str = "time,commit,day,impl,input,stage,median_ns,allocations";
+// from before the input column
+const OLD_HEADER: &str = "time,commit,day,impl,stage,median_ns,allocations";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+	// seconds since the epoch, the same for everything saved at once
+	pub time: u64,
+	pub commit: String,
+	pub day: u32,
+	pub name: String,
+	// what it ran on, as from input_hash. empty for results saved before
+	// this was recorded, which then aren't comparable to anything.
+	pub input: String,
+	// "parse", "part 1" or "part 2"
+	pub stage: String,
+	pub median: Duration,
+	// only with the alloc-stats feature
+	pub allocations: Option<usize>,
+}
+
+impl Record {
+	fn parse(line: &str) -> Result<Record> {
+		let fields: Vec<&str> = line.split(',').collect();
+		let (time, commit, day, name, input, stage, median, allocations) = match fields[..] {
+			[time, commit, day, name, input, stage, median, allocations] =>
+				(time, commit, day, name, input, stage, median, allocations),
+			// saved before there was an input column
+			[time, commit, day, name, stage, median, allocations] =>
+				(time, commit, day, name, "", stage, median, allocations),
+			_ => return Err(eyre!("expected {} fields", HEADER.split(',').count())),
+		};
+		let number = |field: &str, what: &str| field.parse::<u64>()
+			.map_err(|_| eyre!("bad {}: {:?}", what, field));
+
+		Ok(Record {
+			time: number(time, "time")?,
+			commit: commit.to_string(),
+			day: number(day, "day")? as u32,
+			name: name.to_string(),
+			input: input.to_string(),
+			stage: stage.to_string(),
+			median: Duration::from_nanos(number(median, "median")?),
+			allocations: match allocations {
+				"" => None,
+				n => Some(number(n, "number of allocations")? as usize),
+			},
+		})
+	}
+
+	fn to_csv(&self) -> String {
+		format!("{},{},{},{},{},{},{},{}", self.time, self.commit, self.day, self.name,
+			self.input, self.stage, self.median.as_nanos(), self.allocations.map_or(String::new(), |n| n.to_string()))
+	}
+
+	fn same_benchmark(&self, other: &Record) -> bool {
+		!self.input.is_empty()
+			&& (self.day, &self.name, &self.input, &self.stage)
+				== (other.day, &other.name, &other.input, &other.stage)
+	}
+}
+
+// everything saved so far, oldest first. no file just means no history yet.
+pub fn load(path: &Path) -> Result<Vec<Record>> {
+	let text = match fs::read_to_string(path) {
+		Ok(text) => text,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+		Err(e) => return Err(e).wrap_err_with(|| format!("couldn't read {}", path.display())),
+	};
+
+	text.lines().enumerate()
+		.filter(|&(_, line)| line != HEADER && line != OLD_HEADER && !line.is_empty())
+		.map(|(i, line)| Record::parse(line)
+			.wrap_err_with(|| format!("{}:{}: bad benchmark record", path.display(), i + 1)))
+		.collect()
+}
+
+pub fn append(path: &Path, records: &[Record]) -> Result<()> {
+	let new = !path.exists();
+	let mut file = fs::OpenOptions::new().create(true).append(true).open(path)
+		.wrap_err_with(|| format!("couldn't open {}", path.display()))?;
+
+	let mut text = String::new();
+	if new {
+		text += HEADER;
+		text.push('\n');
+	}
+	for record in records {
+		text += &record.to_csv();
+		text.push('\n');
+	}
+
+	file.write_all(text.as_bytes())
+		.wrap_err_with(|| format!("couldn't write {}", path.display()))
+}
+
+// the last saved result of the same benchmark
+pub fn baseline<'a>(history: &'a [Record], record: &Record) -> Option<&'a Record> {
+	history.iter().rev().find(|r| r.same_benchmark(record))
+}
+
+// identifies an input by its contents, so results are only compared when they
+// ran on the same one wherever it was read from. fnv-1a rather than the std
+// hasher, which doesn't promise to hash the same way in the next release.
+pub fn input_hash(input: &str) -> String {
+	let hash = input.bytes()
+		.fold(0xcbf29ce484222325u64, |h, b| (h ^ b as u64).wrapping_mul(0x100000001b3));
+	format!("{:016x}", hash)
+}
+
+// how much slower it got, as a fraction: 0.1 is 10% slower, negative is faster
+pub fn change(baseline: &Record, record: &Record) -> f64 {
+	let before = baseline.median.as_secs_f64();
+	if before > 0.0 { record.median.as_secs_f64() / before - 1.0 } else { 0.0 }
+}
+
+// the short hash of the checked out commit, marked if there are uncommitted
+// changes, or "unknown" outside of a git checkout
+pub fn commit() -> String {
+	let git = |args: &[&str]| process::Command::new("git").args(args).output().ok()
+		.filter(|out| out.status.success())
+		.map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string());
+
+	match (git(&["rev-parse", "--short", "HEAD"]), git(&["status", "--porcelain"])) {
+		(Some(hash), Some(status)) if !status.is_empty() => format!("{}-dirty", hash),
+		(Some(hash), _) => hash,
+		(None, _) => "unknown".to_string(),
+	}
+}
+
+pub fn now() -> u64 {
+	SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |t| t.as_secs())
+}
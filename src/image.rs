@@ -0,0 +1,119 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use eyre::{Result, WrapErr, eyre};
+
+use crate::grid::Grid;
+use crate::render::{Color, Render};
+use crate::solution::Part;
+use crate::{d5, d15, d17, d25};
+
+pub type Rgb = [u8; 3];
+
+// a picture with one pixel per cell, written as a binary pgm or ppm. those
+// are about the simplest formats there are, and anything can convert them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Image {
+	Gray(Grid<u8>),
+	Rgb(Grid<Rgb>),
+}
+
+pub fn rgb(color: Color) -> Rgb {
+	match color {
+		Color::Red => [220, 50, 47],
+		Color::Green => [80, 200, 60],
+		Color::Yellow => [240, 200, 40],
+		Color::Blue => [50, 110, 220],
+		Color::Magenta => [200, 60, 200],
+		Color::Cyan => [40, 200, 200],
+		Color::White => [255, 255, 255],
+		Color::Gray => [90, 90, 90],
+	}
+}
+
+impl Image {
+	// the same picture as on the terminal, with every character a pixel in
+	// its colour. uncoloured characters are white, blanks black.
+	pub fn from_render(picture: &dyn Render) -> Image {
+		let (width, height) = picture.size();
+		Image::Rgb(Grid::from_fn(width, height, |x, y| match picture.cell(x, y) {
+			(_, Some(color)) => rgb(color),
+			(' ', None) => [0, 0, 0],
+			(_, None) => [255, 255, 255],
+		}))
+	}
+
+	pub fn extension(&self) -> &'static str {
+		match self {
+			Image::Gray(_) => "pgm",
+			Image::Rgb(_) => "ppm",
+		}
+	}
+
+	pub fn write(&self, mut out: impl Write) -> io::Result<()> {
+		match self {
+			Image::Gray(grid) => {
+				write!(out, "P5\n{} {}\n255\n", grid.width(), grid.height())?;
+				out.write_all(&grid.cells().copied().collect::<Vec<u8>>())?;
+			}
+			Image::Rgb(grid) => {
+				write!(out, "P6\n{} {}\n255\n", grid.width(), grid.height())?;
+				out.write_all(&grid.cells().flatten().copied().collect::<Vec<u8>>())?;
+			}
+		}
+		out.flush()
+	}
+}
+
+// numbered image files in a directory, like day25-0000.ppm, day25-0001.ppm,
+// in the order they're saved, so that a series makes an animation
+pub struct Images {
+	dir: PathBuf,
+	day: u32,
+	written: Vec<PathBuf>,
+}
+
+impl Images {
+	pub fn new(dir: &Path, day: u32) -> Images {
+		Images { dir: dir.to_path_buf(), day, written: Vec::new() }
+	}
+
+	pub fn save(&mut self, image: &Image) -> Result<()> {
+		if self.written.is_empty() {
+			fs::create_dir_all(&self.dir)
+				.wrap_err_with(|| format!("couldn't create {}", self.dir.display()))?;
+		}
+
+		let name = format!("day{:02}-{:04}.{}", self.day, self.written.len(), image.extension());
+		let path = self.dir.join(name);
+		File::create(&path)
+			.and_then(|file| image.write(BufWriter::new(file)))
+			.wrap_err_with(|| format!("couldn't write {}", path.display()))?;
+		self.written.push(path);
+
+		Ok(())
+	}
+
+	pub fn written(&self) -> &[PathBuf] {
+		&self.written
+	}
+}
+
+// saves pictures of a day from its raw input, for the part where it matters
+pub struct Exporter {
+	pub day: u32,
+	pub export: fn(&str, Part, &mut Images) -> Result<()>,
+}
+
+pub static EXPORTERS: &[Exporter] = &[
+	Exporter { day: 5, export: d5::export },
+	Exporter { day: 15, export: d15::export },
+	Exporter { day: 17, export: d17::export },
+	Exporter { day: 25, export: d25::export },
+];
+
+pub fn find(day: u32) -> Result<&'static Exporter> {
+	EXPORTERS.iter().find(|e| e.day == day)
+		.ok_or_else(|| eyre!("day {} can't be exported as images", day))
+}
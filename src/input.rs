@@ -0,0 +1,63 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use eyre::{Result, WrapErr, bail};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+	Stdin,
+	File(PathBuf),
+}
+
+impl Source {
+	// an explicit path wins, with "-" meaning stdin. otherwise the day's file
+	// under inputs/ is used.
+	pub fn resolve(day: u32, path: Option<&str>, example: bool) -> Source {
+		match path {
+			Some("-") => Source::Stdin,
+			Some(path) => Source::File(path.into()),
+			None => Source::File(default_path(day, example)),
+		}
+	}
+
+	pub fn read(&self) -> Result<String> {
+		match self {
+			Source::Stdin => read_stdin(),
+			Source::File(path) => read_file(path),
+		}
+	}
+}
+
+impl fmt::Display for Source {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Source::Stdin => write!(f, "<stdin>"),
+			Source::File(path) => write!(f, "{}", path.display()),
+		}
+	}
+}
+
+pub fn default_path(day: u32, example: bool) -> PathBuf {
+	let suffix = if example { ".example" } else { "" };
+	Path::new("inputs").join(format!("day{:02}{}.txt", day, suffix))
+}
+
+pub fn read(mut r: impl Read) -> Result<String> {
+	let mut input = String::new();
+	r.read_to_string(&mut input).wrap_err("couldn't read input")?;
+	Ok(input)
+}
+
+pub fn read_stdin() -> Result<String> {
+	read(io::stdin())
+}
+
+pub fn read_file(path: &Path) -> Result<String> {
+	match fs::read_to_string(path) {
+		Err(e) if e.kind() == io::ErrorKind::NotFound =>
+			bail!("no input at {} (pass --input <path>, or - for stdin)", path.display()),
+		r => r.wrap_err_with(|| format!("couldn't read input {}", path.display())),
+	}
+}
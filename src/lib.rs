@@ -0,0 +1,37 @@
+pub mod input;
+pub mod diagnostic;
+pub mod generate;
+pub mod grid;
+pub mod vector;
+pub mod solution;
+pub mod days;
+pub mod answers;
+pub mod bench;
+pub mod history;
+pub mod outcome;
+pub mod pool;
+pub mod serve;
+pub mod trace;
+pub mod cancel;
+pub mod progress;
+pub mod checkpoint;
+pub mod alloc;
+pub mod render;
+pub mod image;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
+
+pub mod d1;
+pub mod d2;
+pub mod d3;
+pub mod d4;
+pub mod d5;
+pub mod d15;
+pub mod d16;
+pub mod d17;
+pub mod d18;
+pub mod d19;
+pub mod d24;
+pub mod d25;
@@ -0,0 +1,82 @@
+use std::any::Any;
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+use crate::alloc::{self, Stats};
+use crate::days::Day;
+use crate::solution::{Parsed, Part};
+
+// the result of solving one part of a day on one input, ready to be printed
+// as text or json
+#[derive(Debug, Clone, Serialize)]
+pub struct Outcome {
+	pub day: u32,
+	#[serde(rename = "impl")]
+	pub name: &'static str,
+	pub part: Part,
+	pub input: String,
+	pub answer: Option<String>,
+	#[serde(rename = "duration_ms", serialize_with = "millis")]
+	pub duration: Duration,
+	pub error: Option<String>,
+	// only with the alloc-stats feature
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub memory: Option<Stats>,
+}
+
+impl Outcome {
+	// solves a single part from the input parsed once for all of them, so the
+	// duration is just the solving
+	pub fn solve(solution: &Day, parsed: &Parsed, source: &str, part: Part) -> Outcome {
+		let start = Instant::now();
+		let (result, memory) = alloc::measure(|| (solution.solve)(parsed, part));
+		let duration = start.elapsed();
+
+		let (answer, error) = match result {
+			Ok(answer) => (Some(answer), None),
+			Err(e) => (None, Some(format!("{:#}", e))),
+		};
+
+		Outcome {
+			day: solution.day,
+			name: solution.name,
+			part,
+			input: source.to_string(),
+			answer,
+			duration,
+			error,
+			memory,
+		}
+	}
+
+	// for when there's no answer because the input couldn't even be read or
+	// parsed
+	pub fn failed(solution: &Day, source: &str, part: Part, error: &eyre::Report) -> Outcome {
+		Outcome {
+			day: solution.day,
+			name: solution.name,
+			part,
+			input: source.to_string(),
+			answer: None,
+			duration: Duration::ZERO,
+			error: Some(format!("{:#}", error)),
+			memory: None,
+		}
+	}
+
+	pub fn is_ok(&self) -> bool {
+		self.error.is_none()
+	}
+}
+
+fn millis<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+	s.serialize_f64(d.as_secs_f64() * 1000.0)
+}
+
+// what a caught panic said, if it was a plain message
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+	payload.downcast_ref::<&str>().map(|s| s.to_string())
+		.or_else(|| payload.downcast_ref::<String>().cloned())
+		.unwrap_or_default()
+}
@@ -0,0 +1,37 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+// calls f on every item from at most `workers` threads at a time and returns
+// the results in the order of the items. an item that f panics on gets the
+// panic instead, and the rest still get done.
+pub fn map<T, R, F>(items: &[T], workers: usize, f: F) -> Vec<thread::Result<R>>
+		where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+	let next = AtomicUsize::new(0);
+	let results: Mutex<Vec<Option<thread::Result<R>>>> =
+		Mutex::new(items.iter().map(|_| None).collect());
+
+	thread::scope(|scope| {
+		for _ in 0..usize::max(1, usize::min(workers, items.len())) {
+			scope.spawn(|| loop {
+				let i = next.fetch_add(1, Ordering::Relaxed);
+				let item = match items.get(i) {
+					Some(item) => item,
+					None => break,
+				};
+				let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)));
+				results.lock().unwrap()[i] = Some(result);
+			});
+		}
+	});
+
+	results.into_inner().unwrap()
+		.into_iter()
+		.map(|r| r.expect("worker didn't finish"))
+		.collect()
+}
+
+pub fn default_workers() -> usize {
+	thread::available_parallelism().map_or(1, |n| n.get())
+}
@@ -0,0 +1,88 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+// progress reports from long searches, to stderr like the trace messages.
+// nothing is printed unless they're turned on.
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+const INTERVAL: Duration = Duration::from_secs(1);
+
+pub fn set_enabled(enabled: bool) {
+	ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+	ENABLED.load(Ordering::Relaxed)
+}
+
+pub struct Progress {
+	label: String,
+	total: u64,
+	// where this run started, which isn't 0 after resuming
+	first: u64,
+	start: Instant,
+	last_report: Instant,
+}
+
+impl Progress {
+	pub fn new(label: impl Into<String>, total: u64, first: u64) -> Progress {
+		let now = Instant::now();
+		Progress { label: label.into(), total, first, start: now, last_report: now }
+	}
+
+	// reports how far along it is, at most once every second
+	pub fn update(&mut self, done: u64) {
+		if enabled() && self.last_report.elapsed() >= INTERVAL {
+			self.last_report = Instant::now();
+			eprintln!("[{}] {}", self.label, self.report(done));
+		}
+	}
+
+	pub fn finish(&mut self) {
+		if enabled() {
+			eprintln!("[{}] {}", self.label, self.report(self.total));
+		}
+	}
+
+	pub fn report(&self, done: u64) -> Report {
+		let elapsed = self.start.elapsed();
+		let rate = done.saturating_sub(self.first) as f64 / elapsed.as_secs_f64();
+		let remaining = self.total.saturating_sub(done) as f64;
+		Report {
+			done,
+			total: self.total,
+			rate,
+			eta: (rate > 0.0).then(|| Duration::from_secs_f64(remaining / rate)),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Report {
+	pub done: u64,
+	pub total: u64,
+	// per second
+	pub rate: f64,
+	pub eta: Option<Duration>,
+}
+
+impl Report {
+	pub fn percent(&self) -> f64 {
+		match self.total {
+			0 => 100.0,
+			total => self.done as f64 * 100.0 / total as f64,
+		}
+	}
+}
+
+impl fmt::Display for Report {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{:.1}% ({} of {}), {:.1}/s", self.percent(), self.done, self.total, self.rate)?;
+		match self.eta {
+			Some(eta) => write!(f, ", eta {:.1?}", eta),
+			None => write!(f, ", eta unknown"),
+		}
+	}
+}
@@ -0,0 +1,227 @@
+use std::fs::File;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use eyre::{Result, eyre};
+
+use crate::solution::Part;
+use crate::{d5, d17, d25};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+	Red,
+	Green,
+	Yellow,
+	Blue,
+	Magenta,
+	Cyan,
+	White,
+	Gray,
+}
+
+impl Color {
+	fn ansi(self) -> &'static str {
+		match self {
+			Color::Red => "\x1b[31m",
+			Color::Green => "\x1b[32m",
+			Color::Yellow => "\x1b[33m",
+			Color::Blue => "\x1b[34m",
+			Color::Magenta => "\x1b[35m",
+			Color::Cyan => "\x1b[36m",
+			Color::White => "\x1b[97m",
+			Color::Gray => "\x1b[90m",
+		}
+	}
+}
+
+const RESET: &str = "\x1b[0m";
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+// something that can be drawn as a rectangle of characters, (0, 0) being
+// the top left
+pub trait Render {
+	fn size(&self) -> (usize, usize);
+	fn cell(&self, x: usize, y: usize) -> (char, Option<Color>);
+}
+
+// the part of a picture that gets drawn. anything missing means all of it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Viewport {
+	pub x: usize,
+	pub y: usize,
+	pub width: Option<usize>,
+	pub height: Option<usize>,
+}
+
+impl std::str::FromStr for Viewport {
+	type Err = eyre::Report;
+
+	// WxH, optionally followed by +X+Y
+	fn from_str(s: &str) -> Result<Viewport> {
+		let bad = || eyre!("bad viewport: {:?}, expected WxH or WxH+X+Y", s);
+		let (size, offset) = match s.split_once('+') {
+			Some((size, offset)) => (size, Some(offset)),
+			None => (s, None),
+		};
+		let (w, h) = size.split_once('x').ok_or_else(bad)?;
+		let (x, y) = match offset.map(|o| o.split_once('+')) {
+			None => (0, 0),
+			Some(Some((x, y))) => (x.parse().map_err(|_| bad())?, y.parse().map_err(|_| bad())?),
+			Some(None) => return Err(bad()),
+		};
+
+		Ok(Viewport {
+			x,
+			y,
+			width: Some(w.parse().map_err(|_| bad())?),
+			height: Some(h.parse().map_err(|_| bad())?),
+		})
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+	pub color: bool,
+	// clear the screen before every frame, instead of just separating them
+	pub clear: bool,
+	// frames per second at most; 0 is as fast as possible
+	pub fps: f64,
+	pub viewport: Viewport,
+}
+
+impl Options {
+	// what makes sense for stdout: colours and animation on a terminal, plain
+	// frames one after the other otherwise
+	pub fn for_stdout() -> Options {
+		use std::io::IsTerminal;
+
+		let terminal = io::stdout().is_terminal();
+		let env = |name| std::env::var(name).ok().and_then(|v| v.parse::<usize>().ok());
+		let size = terminal_size();
+		let viewport = match terminal {
+			// leave a line for the footer
+			true => Viewport {
+				width: size.map(|(w, _)| w).or_else(|| env("COLUMNS")),
+				height: size.map(|(_, h)| h).or_else(|| env("LINES")).map(|h| h.saturating_sub(1)),
+				..Viewport::default()
+			},
+			false => Viewport::default(),
+		};
+
+		Options {
+			color: terminal && std::env::var_os("NO_COLOR").is_none(),
+			clear: terminal,
+			fps: if terminal { 10.0 } else { 0.0 },
+			viewport,
+		}
+	}
+}
+
+// the terminal's columns and lines, as stty sees them. stty asks its stdin,
+// which might be the puzzle input, so it gets the terminal itself instead.
+fn terminal_size() -> Option<(usize, usize)> {
+	let tty = File::open("/dev/tty").ok()?;
+	let output = Command::new("stty").arg("size")
+		.stdin(tty)
+		.stderr(Stdio::null())
+		.output().ok()?;
+	if !output.status.success() {
+		return None;
+	}
+	let text = String::from_utf8(output.stdout).ok()?;
+	let (lines, columns) = text.trim().split_once(' ')?;
+	match (columns.parse().ok()?, lines.parse().ok()?) {
+		// not a terminal that knows its size
+		(0, _) | (_, 0) => None,
+		size => Some(size),
+	}
+}
+
+pub fn draw(picture: &dyn Render, options: &Options) -> String {
+	let (width, height) = picture.size();
+	let Viewport { x: x0, y: y0, .. } = options.viewport;
+	let x1 = options.viewport.width.map_or(width, |w| usize::min(width, x0.saturating_add(w)));
+	let y1 = options.viewport.height.map_or(height, |h| usize::min(height, y0.saturating_add(h)));
+
+	let mut out = String::new();
+	for y in y0..y1 {
+		let mut current = None;
+		for x in x0..x1 {
+			let (c, color) = picture.cell(x, y);
+			if options.color && color != current {
+				out += color.map_or(RESET, Color::ansi);
+				current = color;
+			}
+			out.push(c);
+		}
+		if current.is_some() {
+			out += RESET;
+		}
+		out.push('\n');
+	}
+
+	if (x0, y0, x1, y1) != (0, 0, width, height) {
+		out += &format!("[{}x{} of {}x{} at {},{}]\n",
+			x1.saturating_sub(x0), y1.saturating_sub(y0), width, height, x0, y0);
+	}
+
+	out
+}
+
+// draws frames one after the other, no faster than the frame rate
+pub struct Screen {
+	out: Box<dyn Write>,
+	options: Options,
+	last: Option<Instant>,
+}
+
+impl Screen {
+	pub fn new(out: Box<dyn Write>, options: Options) -> Screen {
+		Screen { out, options, last: None }
+	}
+
+	pub fn frame(&mut self, picture: &dyn Render) -> Result<()> {
+		let frame = draw(picture, &self.options);
+
+		if let Some(last) = self.last {
+			if self.options.fps > 0.0 {
+				let interval = Duration::from_secs_f64(1.0 / self.options.fps);
+				if let Some(wait) = interval.checked_sub(last.elapsed()) {
+					thread::sleep(wait);
+				}
+			}
+			if !self.options.clear {
+				writeln!(self.out)?;
+			}
+		}
+
+		if self.options.clear {
+			write!(self.out, "{}", CLEAR)?;
+		}
+		write!(self.out, "{}", frame)?;
+		self.out.flush()?;
+		self.last = Some(Instant::now());
+
+		Ok(())
+	}
+}
+
+// draws a day from its raw input. the part only matters for days where the
+// picture is different for each.
+pub struct Renderer {
+	pub day: u32,
+	pub render: fn(&str, Part, &mut Screen) -> Result<()>,
+}
+
+pub static RENDERERS: &[Renderer] = &[
+	Renderer { day: 5, render: d5::render },
+	Renderer { day: 17, render: d17::render },
+	Renderer { day: 25, render: d25::render },
+];
+
+pub fn find(day: u32) -> Result<&'static Renderer> {
+	RENDERERS.iter().find(|r| r.day == day)
+		.ok_or_else(|| eyre!("day {} can't be rendered", day))
+}
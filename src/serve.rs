@@ -0,0 +1,174 @@
+use std::io::{BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use eyre::{Report, Result, bail, eyre};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::cancel::{self, TimedOut};
+use crate::days;
+use crate::diagnostic::ParseError;
+use crate::outcome::panic_message;
+use crate::solution::Part;
+
+// answers requests like {"day": 16, "part": 2, "input": "..."}, one json
+// object per line, with one response per line in the same order
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Request {
+	// anything, handed back in the response to match them up
+	#[serde(default)]
+	id: Value,
+	day: u32,
+	part: u8,
+	input: String,
+	#[serde(default, rename = "impl")]
+	name: Option<String>,
+	// seconds, instead of the server's default
+	#[serde(default)]
+	timeout: Option<f64>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Response {
+	pub id: Value,
+	pub day: Option<u32>,
+	#[serde(rename = "impl")]
+	pub name: Option<&'static str>,
+	pub part: Option<Part>,
+	pub answer: Option<String>,
+	// parsing and solving together
+	pub duration_ms: Option<f64>,
+	pub error: Option<Error>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Error {
+	pub kind: ErrorKind,
+	pub message: String,
+	// where in the input a parse error is, counting from 1
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub line: Option<usize>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub column: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+	// not json, or not a request
+	BadRequest,
+	// no such day, part or implementation
+	NotFound,
+	Parse,
+	Solve,
+	Timeout,
+	Panic,
+}
+
+impl Error {
+	fn new(kind: ErrorKind, e: &Report) -> Error {
+		let location = e.chain().find_map(|e| e.downcast_ref::<ParseError>());
+		Error {
+			kind,
+			message: format!("{:#}", e),
+			line: location.map(|l| l.line),
+			column: location.map(|l| l.columns.start + 1),
+		}
+	}
+}
+
+pub fn handle(line: &str, timeout: Option<Duration>) -> Response {
+	let request: Request = match serde_json::from_str(line) {
+		Ok(request) => request,
+		Err(e) => {
+			// still answer to the id, if there is one
+			let id = serde_json::from_str::<Value>(line).ok()
+				.and_then(|v| v.get("id").cloned())
+				.unwrap_or_default();
+			let error = Error::new(ErrorKind::BadRequest, &Report::new(e));
+			return Response { id, error: Some(error), ..Response::default() };
+		}
+	};
+
+	let mut response = Response {
+		id: request.id,
+		day: Some(request.day),
+		..Response::default()
+	};
+
+	let found = days::find(request.day, request.name.as_deref()).and_then(|solution| {
+		match request.part {
+			1 => Ok((solution, Part::One)),
+			2 => Ok((solution, Part::Two)),
+			n => bail!("bad part: {}, expected 1 or 2", n),
+		}
+	});
+	let (solution, part) = match found {
+		Ok((solution, part)) if solution.parts.contains(&part) => (solution, part),
+		Ok((solution, part)) => {
+			let e = eyre!("day {} has no part {}", solution.day, part);
+			response.error = Some(Error::new(ErrorKind::NotFound, &e));
+			return response;
+		}
+		Err(e) => {
+			response.error = Some(Error::new(ErrorKind::NotFound, &e));
+			return response;
+		}
+	};
+	response.name = Some(solution.name);
+	response.part = Some(part);
+
+	let timeout = match request.timeout.map(Duration::try_from_secs_f64) {
+		None => timeout,
+		Some(Ok(t)) if !t.is_zero() => Some(t),
+		Some(_) => {
+			let e = eyre!("bad timeout, expected a positive number of seconds");
+			response.error = Some(Error::new(ErrorKind::BadRequest, &e));
+			return response;
+		}
+	};
+
+	// a panic is as much an answer as any error, and the next request should
+	// still get one
+	let start = Instant::now();
+	let result = panic::catch_unwind(AssertUnwindSafe(|| cancel::with_timeout(timeout, || {
+		let parsed = (solution.parse)(&request.input).map_err(|e| (ErrorKind::Parse, e))?;
+		(solution.solve)(&parsed, part).map_err(|e| (ErrorKind::Solve, e))
+	})));
+	response.duration_ms = Some(start.elapsed().as_secs_f64() * 1000.0);
+
+	match result {
+		Ok(Ok(answer)) => response.answer = Some(answer),
+		Ok(Err((kind, e))) => {
+			let kind = match e.chain().any(|e| e.is::<TimedOut>()) {
+				true => ErrorKind::Timeout,
+				false => kind,
+			};
+			response.error = Some(Error::new(kind, &e));
+		}
+		Err(payload) => {
+			let e = eyre!("panicked: {}", panic_message(&*payload));
+			response.error = Some(Error::new(ErrorKind::Panic, &e));
+		}
+	}
+
+	response
+}
+
+// answers every line until the input ends, skipping blank ones
+pub fn serve(input: impl BufRead, mut output: impl Write, timeout: Option<Duration>)
+		-> Result<()> {
+	for line in input.lines() {
+		let line = line?;
+		if line.trim().is_empty() {
+			continue;
+		}
+		serde_json::to_writer(&mut output, &handle(&line, timeout))?;
+		writeln!(output)?;
+		output.flush()?;
+	}
+	Ok(())
+}
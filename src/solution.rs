@@ -0,0 +1,81 @@
+use std::any::Any;
+use std::fmt;
+use std::str;
+
+use eyre::{Result, eyre, bail};
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+	One,
+	Two,
+}
+
+impl Part {
+	pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Part::One => write!(f, "1"),
+			Part::Two => write!(f, "2"),
+		}
+	}
+}
+
+impl Serialize for Part {
+	fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+		s.serialize_u8(match self {
+			Part::One => 1,
+			Part::Two => 2,
+		})
+	}
+}
+
+impl str::FromStr for Part {
+	type Err = eyre::Report;
+
+	fn from_str(s: &str) -> Result<Part> {
+		match s {
+			"1" => Ok(Part::One),
+			"2" => Ok(Part::Two),
+			_ => bail!("bad part: {:?}, expected 1 or 2", s),
+		}
+	}
+}
+
+pub trait Solution {
+	type Input: Send + Sync + 'static;
+	type Answer1: fmt::Display;
+	type Answer2: fmt::Display;
+
+	// the parts this day actually has, in case it's day 25
+	const PARTS: &'static [Part] = &Part::ALL;
+
+	fn parse(input: &str) -> Result<Self::Input>;
+	fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+	fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+}
+
+// parsed input with the type erased, so that every day can be handled the
+// same way at runtime
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+pub fn parse<S: Solution>(input: &str) -> Result<Parsed> {
+	Ok(Box::new(S::parse(input)?))
+}
+
+pub fn solve<S: Solution>(input: &Parsed, part: Part) -> Result<String> {
+	let input = input.downcast_ref::<S::Input>()
+		.ok_or_else(|| eyre!("input was parsed for a different solution"))?;
+
+	if !S::PARTS.contains(&part) {
+		bail!("there is no part {}", part);
+	}
+
+	Ok(match part {
+		Part::One => S::part1(input)?.to_string(),
+		Part::Two => S::part2(input)?.to_string(),
+	})
+}
@@ -0,0 +1,65 @@
+use std::fmt;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// diagnostics from inside the solutions. nothing is printed unless the
+// verbosity is at least the level of the message, and then only to stderr so
+// answers on stdout stay clean. targets are module paths relative to the
+// crate, like "d16" or "d16::bitvec"; if any are set, only messages from those
+// modules (and their submodules) get through.
+
+pub const DEBUG: u8 = 1;
+pub const TRACE: u8 = 2;
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+static TARGETS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+pub fn set_verbosity(level: u8) {
+	VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn set_targets(targets: Vec<String>) {
+	*TARGETS.write().unwrap() = targets;
+}
+
+pub fn enabled(level: u8, module: &str) -> bool {
+	if VERBOSITY.load(Ordering::Relaxed) < level {
+		return false;
+	}
+
+	let targets = TARGETS.read().unwrap();
+	let module = target(module);
+	targets.is_empty() || targets.iter().any(|t| {
+		module.strip_prefix(t.as_str())
+			.is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+	})
+}
+
+fn target(module: &str) -> &str {
+	match module.split_once("::") {
+		Some((_, rest)) => rest,
+		None => module,
+	}
+}
+
+pub fn log(module: &str, args: fmt::Arguments) {
+	eprintln!("[{}] {}", target(module), args);
+}
+
+#[macro_export]
+macro_rules! debug {
+	($($arg:tt)*) => {
+		if $crate::trace::enabled($crate::trace::DEBUG, module_path!()) {
+			$crate::trace::log(module_path!(), format_args!($($arg)*));
+		}
+	};
+}
+
+#[macro_export]
+macro_rules! trace {
+	($($arg:tt)*) => {
+		if $crate::trace::enabled($crate::trace::TRACE, module_path!()) {
+			$crate::trace::log(module_path!(), format_args!($($arg)*));
+		}
+	};
+}
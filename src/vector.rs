@@ -0,0 +1,241 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops;
+use std::str;
+
+use eyre::{Result, bail};
+
+use crate::diagnostic::{self, Located};
+
+// integer vectors and square matrices of any dimension, for the puzzles that
+// move things around on a grid or in space
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector<const N: usize>(pub [i64; N]);
+
+pub type V2 = Vector<2>;
+pub type V3 = Vector<3>;
+
+impl<const N: usize> Vector<N> {
+	pub const ZERO: Vector<N> = Vector([0; N]);
+
+	pub fn map(self, f: impl FnMut(i64) -> i64) -> Vector<N> {
+		Vector(self.0.map(f))
+	}
+
+	pub fn zip(self, rhs: Vector<N>, mut f: impl FnMut(i64, i64) -> i64) -> Vector<N> {
+		let mut out = self.0;
+		for (a, b) in out.iter_mut().zip(rhs.0) {
+			*a = f(*a, b);
+		}
+		Vector(out)
+	}
+
+	pub fn dot(self, rhs: Vector<N>) -> i64 {
+		self.0.iter().zip(rhs.0).map(|(a, b)| a * b).sum()
+	}
+
+	pub fn abs(self) -> Vector<N> {
+		self.map(i64::abs)
+	}
+
+	// one step in the direction of this vector along every axis, so that
+	// adding it repeatedly walks horizontal, vertical and diagonal lines
+	pub fn signum(self) -> Vector<N> {
+		self.map(i64::signum)
+	}
+
+	pub fn manhattan(self) -> i64 {
+		self.0.iter().map(|c| c.abs()).sum()
+	}
+
+	pub fn chebyshev(self) -> i64 {
+		self.0.iter().map(|c| c.abs()).max().unwrap_or(0)
+	}
+
+	pub fn manhattan_distance(self, rhs: Vector<N>) -> i64 {
+		(self - rhs).manhattan()
+	}
+
+	pub fn chebyshev_distance(self, rhs: Vector<N>) -> i64 {
+		(self - rhs).chebyshev()
+	}
+}
+
+impl<const N: usize> Default for Vector<N> {
+	fn default() -> Vector<N> {
+		Vector::ZERO
+	}
+}
+
+impl<const N: usize> From<[i64; N]> for Vector<N> {
+	fn from(v: [i64; N]) -> Vector<N> {
+		Vector(v)
+	}
+}
+
+impl<const N: usize> ops::Index<usize> for Vector<N> {
+	type Output = i64;
+
+	fn index(&self, i: usize) -> &i64 {
+		&self.0[i]
+	}
+}
+
+impl<const N: usize> ops::IndexMut<usize> for Vector<N> {
+	fn index_mut(&mut self, i: usize) -> &mut i64 {
+		&mut self.0[i]
+	}
+}
+
+impl<const N: usize> ops::Add for Vector<N> {
+	type Output = Vector<N>;
+
+	#[inline]
+	fn add(self, rhs: Vector<N>) -> Vector<N> {
+		self.zip(rhs, |a, b| a + b)
+	}
+}
+
+impl<const N: usize> ops::Sub for Vector<N> {
+	type Output = Vector<N>;
+
+	#[inline]
+	fn sub(self, rhs: Vector<N>) -> Vector<N> {
+		self.zip(rhs, |a, b| a - b)
+	}
+}
+
+impl<const N: usize> ops::Neg for Vector<N> {
+	type Output = Vector<N>;
+
+	#[inline]
+	fn neg(self) -> Vector<N> {
+		self.map(|a| -a)
+	}
+}
+
+impl<const N: usize> ops::Mul<i64> for Vector<N> {
+	type Output = Vector<N>;
+
+	#[inline]
+	fn mul(self, rhs: i64) -> Vector<N> {
+		self.map(|a| a * rhs)
+	}
+}
+
+impl<const N: usize> ops::Div<i64> for Vector<N> {
+	type Output = Vector<N>;
+
+	#[inline]
+	fn div(self, rhs: i64) -> Vector<N> {
+		self.map(|a| a / rhs)
+	}
+}
+
+impl<const N: usize> ops::AddAssign for Vector<N> {
+	fn add_assign(&mut self, rhs: Vector<N>) {
+		*self = *self + rhs;
+	}
+}
+
+impl<const N: usize> ops::SubAssign for Vector<N> {
+	fn sub_assign(&mut self, rhs: Vector<N>) {
+		*self = *self - rhs;
+	}
+}
+
+impl<const N: usize> ops::MulAssign<i64> for Vector<N> {
+	fn mul_assign(&mut self, rhs: i64) {
+		*self = *self * rhs;
+	}
+}
+
+impl<const N: usize> Sum for Vector<N> {
+	fn sum<I: Iterator<Item = Vector<N>>>(iter: I) -> Vector<N> {
+		iter.fold(Vector::ZERO, |a, b| a + b)
+	}
+}
+
+impl<const N: usize> fmt::Display for Vector<N> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for (i, c) in self.0.iter().enumerate() {
+			if i > 0 {
+				write!(f, ",")?;
+			}
+			write!(f, "{}", c)?;
+		}
+
+		Ok(())
+	}
+}
+
+// "x,y" or "x,y,z", depending on the dimension
+impl<const N: usize> str::FromStr for Vector<N> {
+	type Err = eyre::Report;
+
+	fn from_str(s: &str) -> Result<Vector<N>> {
+		let coords: Vec<&str> = s.split(',').collect();
+		if coords.len() != N {
+			bail!(Located::new(s, s, format!("expected {} coords, got {}", N, coords.len())));
+		}
+
+		let mut v = Vector::ZERO;
+		for (c, text) in v.0.iter_mut().zip(coords) {
+			*c = diagnostic::locate(s, text, text.trim().parse())?;
+		}
+
+		Ok(v)
+	}
+}
+
+// stored as rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Matrix<const N: usize>(pub [Vector<N>; N]);
+
+pub type M2 = Matrix<2>;
+pub type M3 = Matrix<3>;
+
+impl<const N: usize> Matrix<N> {
+	pub fn identity() -> Matrix<N> {
+		let mut m = Matrix([Vector::ZERO; N]);
+		for i in 0..N {
+			m.0[i][i] = 1;
+		}
+		m
+	}
+
+	pub fn transpose(self) -> Matrix<N> {
+		let mut m = self;
+		for i in 0..N {
+			for j in 0..N {
+				m.0[i][j] = self.0[j][i];
+			}
+		}
+		m
+	}
+}
+
+impl<const N: usize> From<[[i64; N]; N]> for Matrix<N> {
+	fn from(rows: [[i64; N]; N]) -> Matrix<N> {
+		Matrix(rows.map(Vector))
+	}
+}
+
+impl<const N: usize> ops::Mul<Vector<N>> for Matrix<N> {
+	type Output = Vector<N>;
+
+	#[inline]
+	fn mul(self, rhs: Vector<N>) -> Vector<N> {
+		Vector(self.0.map(|row| row.dot(rhs)))
+	}
+}
+
+impl<const N: usize> ops::Mul for Matrix<N> {
+	type Output = Matrix<N>;
+
+	fn mul(self, rhs: Matrix<N>) -> Matrix<N> {
+		let columns = rhs.transpose();
+		Matrix(self.0.map(|row| columns * row))
+	}
+}
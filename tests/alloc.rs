@@ -0,0 +1,23 @@
+use advent_of_code_2021::alloc;
+
+// counts with the alloc-stats feature, and doesn't pretend to without it
+#[test]
+fn measure() {
+	let (v, stats) = alloc::measure(|| {
+		let big = vec![0u8; 1 << 20];
+		drop(big);
+		vec![1u64; 16]
+	});
+	assert_eq!(v.len(), 16);
+
+	match alloc::enabled() {
+		false => assert_eq!(stats, None),
+		true => {
+			let stats = stats.unwrap();
+			assert!(stats.allocations >= 2);
+			assert!(stats.bytes >= (1 << 20) + 128);
+			assert!(stats.peak_bytes >= 1 << 20);
+			assert!(stats.peak_bytes < 2 << 20);
+		}
+	}
+}
@@ -0,0 +1,36 @@
+use std::time::{Duration, Instant};
+
+use advent_of_code_2021::cancel::{self, TimedOut};
+use advent_of_code_2021::days;
+use advent_of_code_2021::generate;
+use advent_of_code_2021::input::{default_path, read_file};
+use advent_of_code_2021::solution::Part;
+
+#[test]
+fn timeout() {
+	let day = days::find(17, None).unwrap();
+	let input = read_file(&default_path(17, true)).unwrap();
+
+	let e = cancel::with_timeout(Some(Duration::from_nanos(1)), || day.run(&input, Part::One))
+		.unwrap_err();
+	let timed_out = e.downcast_ref::<TimedOut>().unwrap();
+	assert!(timed_out.progress.contains("x velocities"), "{}", timed_out);
+
+	// the deadline only lasts as long as with_timeout
+	assert_eq!(day.run(&input, Part::One).unwrap(), "45");
+	assert_eq!(cancel::with_timeout(Some(Duration::from_secs(600)),
+		|| day.run(&input, Part::One)).unwrap(), "45");
+}
+
+// day 19 matches scanners on threads of its own, which have to give up too
+#[test]
+fn timeout_in_threads() {
+	let day = days::find(19, None).unwrap();
+	let input = generate::generate(19, 3, Some(150)).unwrap();
+
+	let start = Instant::now();
+	let e = cancel::with_timeout(Some(Duration::from_millis(200)), || day.run(&input, Part::One))
+		.unwrap_err();
+	assert!(e.downcast_ref::<TimedOut>().is_some(), "{:#}", e);
+	assert!(start.elapsed() < Duration::from_secs(10), "took {:.2?}", start.elapsed());
+}
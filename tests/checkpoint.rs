@@ -0,0 +1,47 @@
+use std::fs;
+use std::time::Duration;
+
+use advent_of_code_2021::cancel;
+use advent_of_code_2021::checkpoint;
+use advent_of_code_2021::days;
+use advent_of_code_2021::input::{default_path, read_file};
+use advent_of_code_2021::progress::Report;
+use advent_of_code_2021::solution::Part;
+
+#[test]
+fn resume() {
+	let day = days::find(17, None).unwrap();
+	let input = read_file(&default_path(17, true)).unwrap();
+	let path = std::env::temp_dir().join(format!("aoc-checkpoint-{}.json", std::process::id()));
+	let _ = fs::remove_file(&path);
+
+	// timing out saves where it got to
+	checkpoint::set_path(Some(path.clone()), false);
+	let e = cancel::with_timeout(Some(Duration::from_nanos(1)), || day.run(&input, Part::Two))
+		.unwrap_err();
+	assert!(e.to_string().contains("timed out"), "{}", e);
+	let saved = fs::read_to_string(&path).unwrap();
+	assert!(saved.contains("next_dx"), "{}", saved);
+
+	checkpoint::set_path(Some(path.clone()), true);
+	assert_eq!(day.run(&input, Part::One).unwrap(), "45");
+	assert_eq!(day.run(&input, Part::Two).unwrap(), "112");
+
+	// a checkpoint from another target is ignored
+	fs::write(&path, r#"{"key":"d17 somewhere else","state":{"next_dx":999,"max":null,"successes":0}}"#)
+		.unwrap();
+	assert_eq!(day.run(&input, Part::Two).unwrap(), "112");
+
+	checkpoint::set_path(None, false);
+	let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn report() {
+	let report = Report { done: 250, total: 1000, rate: 50.0, eta: Some(Duration::from_secs(15)) };
+	assert_eq!(report.percent(), 25.0);
+	assert_eq!(report.to_string(), "25.0% (250 of 1000), 50.0/s, eta 15.0s");
+
+	let report = Report { done: 0, total: 1000, rate: 0.0, eta: None };
+	assert_eq!(report.to_string(), "0.0% (0 of 1000), 0.0/s, eta unknown");
+}
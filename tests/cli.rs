@@ -0,0 +1,161 @@
+// runs the aoc binary itself on inputs in a scratch directory
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde_json::{Value, json};
+
+// day 1's example, and something that isn't
+const GOOD: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+const BAD: &str = "199\nnope\n";
+
+// a fresh directory for one test with the given files in it
+fn scratch(name: &str, files: &[(&str, &str)]) -> PathBuf {
+	let dir = std::env::temp_dir().join(format!("aoc-cli-{}-{}", name, std::process::id()));
+	let _ = fs::remove_dir_all(&dir);
+	fs::create_dir_all(&dir).unwrap();
+	for (file, text) in files {
+		fs::write(dir.join(file), text).unwrap();
+	}
+	dir
+}
+
+// the exit status and stdout
+fn aoc(dir: &Path, args: &[&str]) -> (Option<i32>, String) {
+	let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+		.args(args)
+		.current_dir(dir)
+		.output()
+		.unwrap();
+	(output.status.code(), String::from_utf8(output.stdout).unwrap())
+}
+
+// the table row for a file and part, split on whitespace
+fn row<'a>(out: &'a str, file: &str, part: &str) -> Vec<&'a str> {
+	out.lines()
+		.map(|line| line.split_whitespace().collect::<Vec<_>>())
+		.find(|words| words.len() > 2 && words[0] == file && words[1] == part)
+		.unwrap_or_else(|| panic!("no row for {} part {} in:\n{}", file, part, out))
+}
+
+#[test]
+fn inputs_dir() {
+	let dir = scratch("batch", &[("good.txt", GOOD), ("bad.txt", BAD), (".hidden", BAD)]);
+
+	// the bad input fails on its own, without stopping the good one
+	let (status, out) = aoc(&dir, &["run", "1", "--inputs-dir", "."]);
+	assert_eq!(status, Some(1), "{}", out);
+	for (part, answer) in [("1", "7"), ("2", "5")] {
+		let good = row(&out, "good.txt", part);
+		assert_eq!((good[2], *good.last().unwrap()), (answer, "ok"));
+		assert!(row(&out, "bad.txt", part).contains(&"ERROR:"), "{}", out);
+	}
+	assert!(!out.contains(".hidden"), "{}", out);
+	assert!(out.contains("1 of 2 inputs in . solved by day 1"), "{}", out);
+
+	fs::remove_file(dir.join("bad.txt")).unwrap();
+	let (status, out) = aoc(&dir, &["run", "1", "--inputs-dir", ".", "--part", "2"]);
+	assert_eq!(status, Some(0), "{}", out);
+	assert!(out.contains("1 of 1 inputs in . solved by day 1"), "{}", out);
+
+	assert_eq!(aoc(&dir, &["run", "1", "--inputs-dir", ".", "--example"]).0, Some(2));
+	fs::remove_file(dir.join("good.txt")).unwrap();
+	assert_eq!(aoc(&dir, &["run", "1", "--inputs-dir", "."]).0, Some(1));
+
+	fs::remove_dir_all(&dir).unwrap();
+}
+
+// one object per part, with the same fields for answers and errors
+#[test]
+fn json() {
+	let dir = scratch("json", &[("good.txt", GOOD), ("bad.txt", BAD)]);
+	let lines = |out: &str| -> Vec<Value> {
+		out.lines().map(|line| serde_json::from_str(line).unwrap()).collect()
+	};
+
+	let (status, out) = aoc(&dir, &["run", "1", "--input", "good.txt", "--format", "json"]);
+	assert_eq!(status, Some(0), "{}", out);
+	let outcomes = lines(&out);
+	assert_eq!(outcomes.len(), 2);
+	for (outcome, (part, answer)) in outcomes.iter().zip([(1, "7"), (2, "5")]) {
+		let mut fields: Vec<&str> = outcome.as_object().unwrap().keys().map(|k| &**k).collect();
+		// only there with alloc-stats
+		fields.retain(|&k| k != "memory");
+		fields.sort();
+		assert_eq!(fields, ["answer", "day", "duration_ms", "error", "impl", "input", "part"]);
+		assert_eq!(outcome["day"], json!(1));
+		assert_eq!(outcome["impl"], json!("default"));
+		assert_eq!(outcome["part"], json!(part));
+		assert_eq!(outcome["input"], json!("good.txt"));
+		assert_eq!(outcome["answer"], json!(answer));
+		assert!(outcome["duration_ms"].as_f64().is_some_and(|ms| ms >= 0.0));
+		assert_eq!(outcome["error"], Value::Null);
+	}
+
+	let (status, out) = aoc(&dir,
+		&["run", "1", "--input", "bad.txt", "--part", "2", "--format", "json"]);
+	assert_eq!(status, Some(1), "{}", out);
+	let outcomes = lines(&out);
+	assert_eq!(outcomes.len(), 1);
+	assert_eq!(outcomes[0]["part"], json!(2));
+	assert_eq!(outcomes[0]["answer"], Value::Null);
+	assert!(outcomes[0]["error"].as_str().is_some_and(|e| e.starts_with("line 2, column 1:")));
+
+	// the same for every part of every file in a batch
+	let (status, out) = aoc(&dir, &["run", "1", "--inputs-dir", ".", "--format", "json"]);
+	assert_eq!(status, Some(1), "{}", out);
+	let outcomes = lines(&out);
+	let inputs: Vec<(&str, bool)> = outcomes.iter()
+		.map(|o| (o["input"].as_str().unwrap(), o["error"].is_null()))
+		.collect();
+	assert_eq!(inputs,
+		[("./bad.txt", false), ("./bad.txt", false), ("./good.txt", true), ("./good.txt", true)]);
+
+	fs::remove_dir_all(&dir).unwrap();
+}
+
+// every state a part can be in, one line each even for errors
+#[test]
+fn verify() {
+	let answers = "\
+		[\"good.txt\"]\nday = 1\npart1 = 7\npart2 = \"6\"\n\
+		[\"partial.txt\"]\nday = 1\npart1 = \"7\"\n\
+		[\"bad.txt\"]\nday = 1\npart1 = \"1\"\n\
+		[\"gone.txt\"]\nday = 1\npart1 = \"1\"\n";
+	let dir = scratch("verify", &[
+		("good.txt", GOOD), ("partial.txt", GOOD), ("bad.txt", BAD), ("answers.toml", answers),
+	]);
+
+	let (status, out) = aoc(&dir, &["verify", "1", "--answers", "answers.toml"]);
+	assert_eq!(status, Some(1), "{}", out);
+	let expected = [
+		("bad.txt", "1", "1", "line 2, column 1: that's not a number", "ERROR"),
+		("bad.txt", "2", "-", "line 2, column 1: that's not a number", "ERROR"),
+		("gone.txt", "1", "1", "", "no input"),
+		("gone.txt", "2", "-", "", "no input"),
+		("good.txt", "1", "7", "7", "ok"),
+		("good.txt", "2", "6", "5", "MISMATCH"),
+		("partial.txt", "1", "7", "7", "ok"),
+		("partial.txt", "2", "-", "5", "missing answer"),
+	];
+	// the answers have spaces in them, so they're cut out by where the header is
+	let mut lines = out.lines();
+	let header = lines.next().unwrap();
+	let (answers, statuses) = (header.find("answer").unwrap(), header.find("status").unwrap());
+	let rows: Vec<_> = lines.map(|line| {
+		let words: Vec<&str> = line.split_whitespace().collect();
+		(words[3], words[2], words[4], line[answers..statuses].trim(), &line[statuses..])
+	}).collect();
+	assert_eq!(rows.len(), expected.len(), "{}", out);
+	for (row, expected) in rows.into_iter().zip(expected) {
+		assert!(row.4 == expected.4 && row.3.starts_with(expected.3), "{:?} in:\n{}", row, out);
+		assert_eq!((row.0, row.1, row.2), (expected.0, expected.1, expected.2), "{}", out);
+	}
+
+	// nothing wrong, just not all there
+	fs::write(dir.join("answers.toml"), "[\"partial.txt\"]\nday = 1\npart1 = \"7\"\n").unwrap();
+	assert_eq!(aoc(&dir, &["verify", "1", "--answers", "answers.toml"]).0, Some(0));
+
+	fs::remove_dir_all(&dir).unwrap();
+}
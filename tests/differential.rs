@@ -0,0 +1,185 @@
+// days 16 and 18 have two independent implementations each. feed both the
+// same random inputs and check they parse them the same way and come up
+// with the same answers. when they don't, the input is shrunk to something
+// small enough to debug before failing.
+
+use std::fmt::Debug;
+
+use advent_of_code_2021::d16::{self, Packet, Payload};
+use advent_of_code_2021::d18::{string, tree::{self, Node}};
+use advent_of_code_2021::generate;
+use advent_of_code_2021::solution::Solution;
+
+// both results, side by side, if they differ
+fn compare<T: PartialEq + Debug>(what: &str, a: T, b: T) -> Option<String> {
+	match a == b {
+		true => None,
+		false => Some(format!("{}: {:?} vs {:?}", what, a, b)),
+	}
+}
+
+// errors only have to agree on there being one
+fn outcome<T, E>(result: Result<T, E>) -> Option<T> {
+	result.ok()
+}
+
+// keeps replacing the case with a smaller one that still fails, until none
+// of the candidates do
+fn shrink<T: Clone>(mut case: T, candidates: impl Fn(&T) -> Vec<T>,
+		fails: impl Fn(&T) -> bool) -> T {
+	'outer: loop {
+		for candidate in candidates(&case) {
+			if fails(&candidate) {
+				case = candidate;
+				continue 'outer;
+			}
+		}
+		return case;
+	}
+}
+
+fn d16_disagreement(hex: &str) -> Option<String> {
+	let nibble = d16::nibble::Day16::parse(hex);
+	let bitvec = d16::bitvec::Day16::parse(hex);
+	match (nibble, bitvec) {
+		(Ok(a), Ok(b)) => compare("packets", &a, &b)
+			.or_else(|| compare("part 1", outcome(d16::nibble::Day16::part1(&a)),
+				outcome(d16::bitvec::Day16::part1(&b))))
+			.or_else(|| compare("part 2", outcome(d16::nibble::Day16::part2(&a)),
+				outcome(d16::bitvec::Day16::part2(&b)))),
+		(a, b) => compare("parsing", a.is_ok(), b.is_ok()),
+	}
+}
+
+fn smaller_packets(packet: &Packet) -> Vec<Packet> {
+	let mut out = Vec::new();
+	if packet.version != 0 {
+		out.push(Packet { version: 0, ..packet.clone() });
+	}
+
+	match &packet.payload {
+		&Payload::Literal(v) => {
+			if v > 0 {
+				out.push(Packet { version: packet.version, payload: Payload::Literal(0) });
+				out.push(Packet { version: packet.version, payload: Payload::Literal(v / 2) });
+			}
+		}
+		Payload::Operator(op, packets) => {
+			out.extend(packets.iter().cloned());
+			let operator = |packets| Packet {
+				version: packet.version,
+				payload: Payload::Operator(*op, packets),
+			};
+			for i in 0..packets.len() {
+				if packets.len() > 1 && !(5..=7).contains(op) {
+					let mut fewer = packets.clone();
+					fewer.remove(i);
+					out.push(operator(fewer));
+				}
+				for smaller in smaller_packets(&packets[i]) {
+					let mut packets = packets.clone();
+					packets[i] = smaller;
+					out.push(operator(packets));
+				}
+			}
+		}
+	}
+
+	out
+}
+
+#[test]
+fn d16() {
+	for seed in 0..300 {
+		let hex = generate::generate(16, seed, Some(1 + seed as usize % 40)).unwrap();
+		if d16_disagreement(&hex).is_none() {
+			continue;
+		}
+
+		let message = match d16::nibble::Day16::parse(&hex) {
+			Ok(packet) => {
+				let fails = |p: &Packet| p.encode().ok()
+					.and_then(|hex| d16_disagreement(&hex)).is_some();
+				let packet = shrink(packet, smaller_packets, fails);
+				let hex = packet.encode().unwrap();
+				format!("{}\n{:?}\n{}", hex, packet, d16_disagreement(&hex).unwrap())
+			}
+			Err(_) => format!("{}\n{}", hex, d16_disagreement(&hex).unwrap()),
+		};
+		panic!("implementations disagree on seed {}:\n{}", seed, message);
+	}
+}
+
+fn d18_disagreement(numbers: &[Node]) -> Option<String> {
+	let input: String = numbers.iter().map(|n| format!("{}\n", n)).collect();
+	let parsed_tree = tree::Day18::parse(&input);
+	let parsed_string = string::Day18::parse(&input);
+	match (parsed_tree, parsed_string) {
+		(Ok(a), Ok(b)) => {
+			let a_text: Vec<_> = a.iter().map(Node::to_string).collect();
+			compare("numbers", &a_text, &b)
+				.or_else(|| compare("sum", Some(tree::sum(a.clone()).to_string()),
+					outcome(string::sum(b.clone()))))
+				.or_else(|| compare("part 1", outcome(tree::Day18::part1(&a)),
+					outcome(string::Day18::part1(&b))))
+				.or_else(|| compare("part 2", outcome(tree::Day18::part2(&a)),
+					outcome(string::Day18::part2(&b))))
+		}
+		(a, b) => compare("parsing", a.is_ok(), b.is_ok()),
+	}
+}
+
+fn smaller_nodes(node: &Node) -> Vec<Node> {
+	match node {
+		&Node::Number(n) => match n {
+			0 => vec![],
+			_ => vec![Node::Number(0), Node::Number(n / 2)],
+		},
+		Node::Pair(l, r) => {
+			let mut out = vec![(**l).clone(), (**r).clone()];
+			for smaller in smaller_nodes(l) {
+				out.push(Node::Pair(Box::new(smaller), r.clone()));
+			}
+			for smaller in smaller_nodes(r) {
+				out.push(Node::Pair(l.clone(), Box::new(smaller)));
+			}
+			out
+		}
+	}
+}
+
+fn smaller_lists(numbers: &[Node]) -> Vec<Vec<Node>> {
+	let mut out = Vec::new();
+	for i in 0..numbers.len() {
+		let mut fewer = numbers.to_vec();
+		fewer.remove(i);
+		out.push(fewer);
+	}
+	for i in 0..numbers.len() {
+		// every line has to stay a pair
+		for smaller in smaller_nodes(&numbers[i]) {
+			if let Node::Pair(..) = smaller {
+				let mut numbers = numbers.to_vec();
+				numbers[i] = smaller;
+				out.push(numbers);
+			}
+		}
+	}
+	out
+}
+
+#[test]
+fn d18() {
+	for seed in 0..100 {
+		let input = generate::generate(18, seed, Some(1 + seed as usize % 10)).unwrap();
+		let numbers = tree::parse(&input).unwrap();
+		if d18_disagreement(&numbers).is_none() {
+			continue;
+		}
+
+		let numbers = shrink(numbers, |n| smaller_lists(n), |n| d18_disagreement(n).is_some());
+		let input: String = numbers.iter().map(|n| format!("{}\n", n)).collect();
+		panic!("implementations disagree on seed {}:\n{}{}",
+			seed, input, d18_disagreement(&numbers).unwrap());
+	}
+}
@@ -0,0 +1,138 @@
+// runs every implementation of a day against the official example from
+// inputs/ and checks the answers given in the puzzle text
+
+use advent_of_code_2021::days::DAYS;
+use advent_of_code_2021::input;
+use advent_of_code_2021::solution::Part;
+
+fn check(day: u32, expected: &[&str]) {
+	let path = input::default_path(day, true);
+	let example = input::read_file(&path).unwrap();
+
+	let implementations: Vec<_> = DAYS.iter().filter(|d| d.day == day).collect();
+	assert!(!implementations.is_empty(), "day {} isn't implemented", day);
+
+	for solution in implementations {
+		assert_eq!(solution.parts.len(), expected.len());
+		for (&part, &expected) in solution.parts.iter().zip(expected) {
+			let answer = solution.run(&example, part).unwrap_or_else(|e|
+				panic!("day {} ({}) part {} failed: {:#}", day, solution.name, part, e));
+			assert_eq!(answer, expected,
+				"day {} ({}) part {}", day, solution.name, part);
+		}
+	}
+}
+
+fn check_inline(day: u32, part: Part, input: &str, expected: &str) {
+	for solution in DAYS.iter().filter(|d| d.day == day) {
+		let answer = solution.run(input, part).unwrap();
+		assert_eq!(answer, expected,
+			"day {} ({}) part {} on {:?}", day, solution.name, part, input);
+	}
+}
+
+#[test]
+fn d1() {
+	check(1, &["7", "5"]);
+}
+
+#[test]
+fn d2() {
+	check(2, &["150", "900"]);
+}
+
+#[test]
+fn d3() {
+	check(3, &["198", "230"]);
+}
+
+#[test]
+fn d4() {
+	check(4, &["4512", "1924"]);
+}
+
+#[test]
+fn d5() {
+	check(5, &["5", "12"]);
+
+	// far out, but only a few points
+	let input = "1000000000,7 -> 1000000000,9\n1000000002,8 -> 999999999,8\n\
+		999999999,6 -> 1000000002,9\n";
+	check_inline(5, Part::One, input, "1");
+	check_inline(5, Part::Two, input, "3");
+}
+
+#[test]
+fn d15() {
+	check(15, &["40", "315"]);
+}
+
+#[test]
+fn d16() {
+	check(16, &["16", "15"]);
+}
+
+#[test]
+fn d16_version_sums() {
+	for (input, expected) in [
+		("620080001611562C8802118E34", "12"),
+		("C0015000016115A2E0802F182340", "23"),
+		("A0016C880162017C3686B18A3D4780", "31"),
+	] {
+		check_inline(16, Part::One, input, expected);
+	}
+}
+
+#[test]
+fn d16_evaluation() {
+	for (input, expected) in [
+		("C200B40A82", "3"),
+		("04005AC33890", "54"),
+		("880086C3E88112", "7"),
+		("CE00C43D881120", "9"),
+		("D8005AC2A8F0", "1"),
+		("F600BC2D8F", "0"),
+		("9C005AC2F8F0", "0"),
+		("9C0141080250320F1802104A08", "1"),
+	] {
+		check_inline(16, Part::Two, input, expected);
+	}
+}
+
+#[test]
+fn d17() {
+	check(17, &["45", "112"]);
+
+	// above the launch height, and to the left
+	let cases = [
+		("target area: x=2..3, y=3..3\n", "6", "3"),
+		("target area: x=5..8, y=4..6\n", "21", "17"),
+		("target area: x=-30..-20, y=-10..-5\n", "45", "112"),
+	];
+	for (input, highest, hits) in cases {
+		check_inline(17, Part::One, input, highest);
+		check_inline(17, Part::Two, input, hits);
+	}
+}
+
+#[test]
+fn d18() {
+	check(18, &["4140", "3993"]);
+}
+
+#[test]
+fn d19() {
+	check(19, &["79", "3621"]);
+}
+
+// there's no example for the whole puzzle, so this is a made up program with
+// the same shape as the real ones
+#[test]
+fn d24() {
+	check(24, &["94979799759694", "61313153116141"]);
+}
+
+#[test]
+fn d25() {
+	check(25, &["58"]);
+}
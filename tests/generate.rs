@@ -0,0 +1,69 @@
+// generated inputs should be valid for every implementation, whatever the seed
+
+use std::time::Duration;
+
+use advent_of_code_2021::cancel;
+use advent_of_code_2021::d16::{self, Packet};
+use advent_of_code_2021::{d5, d17};
+use advent_of_code_2021::days::DAYS;
+use advent_of_code_2021::generate::{self, GENERATORS};
+use advent_of_code_2021::solution::Solution;
+
+#[test]
+fn solvable() {
+	for generator in GENERATORS {
+		for seed in 0..3 {
+			let input = generate::generate(generator.day, seed, Some(8)).unwrap();
+			for solution in DAYS.iter().filter(|d| d.day == generator.day) {
+				for &part in solution.parts {
+					solution.run(&input, part).unwrap_or_else(|e|
+						panic!("day {} ({}) part {} failed on seed {}: {:#}\n{}",
+							generator.day, solution.name, part, seed, e, input));
+				}
+			}
+		}
+	}
+}
+
+// the biggest target it makes is far enough out that a search over a fixed
+// area would miss the highest throw, which for a target below goes straight
+// back down through y=0 and then to its bottom edge in one step. it should
+// still be solved in time.
+#[test]
+fn d17_large() {
+	let input = generate::generate(17, 1, Some(usize::MAX)).unwrap();
+	let probe = d17::Day17::parse(&input).unwrap();
+	let y0 = probe.target.y.0.abs();
+	assert!(y0 > 1000, "{}", input);
+
+	let timeout = Some(Duration::from_secs(60));
+	let highest = cancel::with_timeout(timeout, || d17::Day17::part1(&probe)).unwrap();
+	assert_eq!(highest, y0 * (y0 - 1) / 2, "{}", input);
+	cancel::with_timeout(timeout, || d17::Day17::part2(&probe)).unwrap();
+}
+
+// coordinates well past what fits in a grid in memory
+#[test]
+fn d5_large() {
+	let input = generate::generate(5, 1, Some(20_000)).unwrap();
+	let lines = d5::Day5::parse(&input).unwrap();
+	assert!(lines.iter().any(|(p, _)| p[0] > 10_000), "{}", input);
+	d5::Day5::part2(&lines).unwrap();
+}
+
+#[test]
+fn deterministic() {
+	for generator in GENERATORS {
+		assert_eq!(generate::generate(generator.day, 42, None).unwrap(),
+			generate::generate(generator.day, 42, None).unwrap());
+	}
+}
+
+#[test]
+fn d16_round_trip() {
+	for hex in ["D2FE28", "38006F45291200", "EE00D40C823060", "9C0141080250320F1802104A08"] {
+		let packet: Packet = d16::nibble::Day16::parse(hex).unwrap();
+		let encoded = packet.encode().unwrap();
+		assert_eq!(d16::nibble::Day16::parse(&encoded).unwrap(), packet, "{}", hex);
+	}
+}
@@ -0,0 +1,30 @@
+use advent_of_code_2021::diagnostic::ParseError;
+use advent_of_code_2021::grid::Grid;
+
+#[test]
+fn parse_and_display() {
+	let grid = Grid::parse_digits("123\n456\n").unwrap();
+	assert_eq!((grid.width(), grid.height()), (3, 2));
+	assert_eq!(grid[(2, 1)], 6);
+	assert_eq!(grid.get((3, 0)), None);
+	assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+	assert_eq!(grid.to_string(), "123\n456");
+
+	let e = Grid::parse_digits("123\n45\n").unwrap_err();
+	assert_eq!(e.downcast_ref::<ParseError>().map(|e| e.line), Some(2));
+	let e = Grid::parse_digits("123\n4x6\n").unwrap_err();
+	assert_eq!(e.downcast_ref::<ParseError>().map(|e| e.columns.clone()), Some(1..2));
+}
+
+#[test]
+fn neighbors() {
+	let grid = Grid::new(3, 3, ());
+	assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+	assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+	assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+
+	let grid = grid.toroidal();
+	assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+		[(0, 2), (2, 0), (1, 0), (0, 1)]);
+	assert_eq!(grid.neighbor((2, 2), (1, 1)), Some((0, 0)));
+}
@@ -0,0 +1,59 @@
+use std::fs;
+use std::time::Duration;
+
+use advent_of_code_2021::history::{self, Record};
+
+fn record(time: u64, stage: &str, micros: u64, allocations: Option<usize>) -> Record {
+	Record {
+		time,
+		commit: format!("c{}", time),
+		day: 15,
+		name: "default".to_string(),
+		input: history::input_hash("1163\n1381\n"),
+		stage: stage.to_string(),
+		median: Duration::from_micros(micros),
+		allocations,
+	}
+}
+
+#[test]
+fn save_and_compare() {
+	let path = std::env::temp_dir().join(format!("aoc-history-{}.csv", std::process::id()));
+	let _ = fs::remove_file(&path);
+	assert_eq!(history::load(&path).unwrap(), vec![]);
+
+	let first = vec![record(1, "parse", 100, Some(3)), record(1, "part 1", 2000, None)];
+	let second = vec![record(2, "part 1", 1000, Some(7))];
+	history::append(&path, &first).unwrap();
+	history::append(&path, &second).unwrap();
+
+	let saved = history::load(&path).unwrap();
+	assert_eq!(saved, [first, second].concat());
+	assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 4);
+
+	// the latest result for the same stage is the baseline
+	let now = record(3, "part 1", 1500, None);
+	let baseline = history::baseline(&saved, &now).unwrap();
+	assert_eq!(baseline.commit, "c2");
+	assert!((history::change(baseline, &now) - 0.5).abs() < 1e-9);
+	assert_eq!(history::baseline(&saved, &record(3, "part 2", 1, None)), None);
+
+	// a different input isn't comparable, even with the same day and stage
+	let other = Record { input: history::input_hash("1163\n1381\n2130\n"), ..now.clone() };
+	assert_ne!(other.input, now.input);
+	assert_eq!(history::baseline(&saved, &other), None);
+
+	// nor is anything saved before the input was recorded
+	fs::write(&path, "time,commit,day,impl,stage,median_ns,allocations\n1,c1,15,default,part 1,1000,\n")
+		.unwrap();
+	let old = history::load(&path).unwrap();
+	assert_eq!((old[0].input.as_str(), old[0].median), ("", Duration::from_micros(1)));
+	assert_eq!(history::baseline(&old, &now), None);
+
+	fs::write(&path, "time,commit,day,impl,input,stage,median_ns,allocations\n1,c1,15,default\n")
+		.unwrap();
+	let e = history::load(&path).unwrap_err();
+	assert!(format!("{:#}", e).contains(":2: bad benchmark record"), "{:#}", e);
+
+	let _ = fs::remove_file(&path);
+}
@@ -0,0 +1,38 @@
+use advent_of_code_2021::d15;
+use advent_of_code_2021::grid::Grid;
+use advent_of_code_2021::image::Image;
+use advent_of_code_2021::input::{default_path, read_file};
+
+#[test]
+fn formats() {
+	let mut out = Vec::new();
+	Image::Gray(Grid::from_fn(2, 1, |x, _| x as u8 * 255)).write(&mut out).unwrap();
+	assert_eq!(out, b"P5\n2 1\n255\n\x00\xff");
+
+	let mut out = Vec::new();
+	Image::Rgb(Grid::new(1, 2, [1, 2, 3])).write(&mut out).unwrap();
+	assert_eq!(out, b"P6\n1 2\n255\n\x01\x02\x03\x01\x02\x03");
+}
+
+// the highlighted path has to be a real path with the answer's risk
+#[test]
+fn d15_path() {
+	let risks = d15::parse(&read_file(&default_path(15, true)).unwrap()).unwrap();
+	for (scale, expected) in [(1, 40), (5, 315)] {
+		let (total, path) = d15::safest_path(&risks, scale).unwrap();
+		assert_eq!(total, expected);
+		assert_eq!(path.first(), Some(&(0, 0)));
+		assert_eq!(path.last(), Some(&(risks.width() * scale - 1, risks.height() * scale - 1)));
+		for pair in path.windows(2) {
+			let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+			assert_eq!(x0.abs_diff(x1) + y0.abs_diff(y1), 1);
+		}
+
+		let w = risks.width();
+		let h = risks.height();
+		let sum: u32 = path[1..].iter()
+			.map(|&(x, y)| (risks[(x % w, y % h)] + (x / w + y / h) as u32 - 1) % 9 + 1)
+			.sum();
+		assert_eq!(sum, total);
+	}
+}
@@ -0,0 +1,86 @@
+// corrupts the example inputs in random ways and checks that every
+// implementation either solves them or returns an error, but never panics
+
+use std::iter;
+use std::panic::{self, AssertUnwindSafe};
+
+use advent_of_code_2021::days::DAYS;
+use advent_of_code_2021::generate::Rng;
+use advent_of_code_2021::input::{default_path, read_file};
+
+// characters that mean something to at least one day, and some that don't
+const CHARS: &[char] = &[
+	'0', '1', '5', '9', '-', ',', '.', '>', 'v', '[', ']', '=', ':', ' ', '\n',
+	'A', 'F', 'x', 'y', 'z', 'w', 'é',
+];
+
+// inputs that made something panic before, tried before the random ones
+const CORPUS: &[(u32, &str)] = &[
+	(2, "up -9223372036854775808\n"),
+];
+
+fn mutate(rng: &mut Rng, input: &str) -> String {
+	let mut chars: Vec<char> = input.chars().collect();
+	for _ in 0..1 + rng.index(3) {
+		let i = rng.index(chars.len() + 1);
+		match rng.index(7) {
+			0 if i < chars.len() => { chars.remove(i); }
+			1 => chars.insert(i, *rng.choose(CHARS)),
+			2 if i < chars.len() => chars[i] = *rng.choose(CHARS),
+			3 => chars.truncate(i),
+			// a whole line gone or repeated
+			4 | 5 => {
+				let text: String = chars.iter().collect();
+				let mut lines: Vec<&str> = text.lines().collect();
+				if lines.is_empty() {
+					continue;
+				}
+				let j = rng.index(lines.len());
+				match rng.chance(1, 2) {
+					true => { lines.remove(j); }
+					false => lines.insert(j, lines[j]),
+				}
+				chars = lines.join("\n").chars().collect();
+			}
+			// digits enough to overflow whatever they end up in
+			6 => {
+				let digits = *rng.choose(&["99999", "2147483647", "4294967295", "4611686018427387904"]);
+				chars.splice(i..i, digits.chars());
+			}
+			_ => {}
+		}
+	}
+	chars.into_iter().collect()
+}
+
+#[test]
+fn no_panics() {
+	panic::set_hook(Box::new(|_| {}));
+
+	let mut failures = Vec::new();
+	for day in DAYS {
+		let input = read_file(&default_path(day.day, true)).unwrap();
+		// day 17's search grows with the target, which adds up
+		let cases = if day.day == 17 { 10 } else { 200 };
+		let mut rng = Rng::new(day.day as u64);
+		let corpus = CORPUS.iter().filter(|&&(d, _)| d == day.day).map(|&(_, s)| s.to_string());
+		let random = (1..cases).map(|_| mutate(&mut rng, &input));
+		'cases: for mutated in iter::once(String::new()).chain(corpus).chain(random) {
+			for &part in day.parts {
+				let result = panic::catch_unwind(AssertUnwindSafe(|| day.run(&mutated, part)));
+				if let Err(e) = result {
+					let message = e.downcast_ref::<String>().cloned()
+						.or_else(|| e.downcast_ref::<&str>().map(|s| s.to_string()))
+						.unwrap_or_default();
+					failures.push(format!("day {} ({}) part {} panicked: {}\n{:?}",
+						day.day, day.name, part, message, mutated));
+					// one example per implementation is enough to go on
+					break 'cases;
+				}
+			}
+		}
+	}
+
+	let _ = panic::take_hook();
+	assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
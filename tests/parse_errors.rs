@@ -0,0 +1,111 @@
+// every implementation should point at the offending part of a malformed line
+
+use advent_of_code_2021::days::DAYS;
+use advent_of_code_2021::diagnostic::ParseError;
+
+fn check(day: u32, input: &str, line: usize, columns: std::ops::Range<usize>) {
+	for solution in DAYS.iter().filter(|d| d.day == day) {
+		let e = (solution.parse)(input).err().unwrap_or_else(||
+			panic!("day {} ({}) accepted {:?}", day, solution.name, input));
+		let e = e.downcast_ref::<ParseError>().unwrap_or_else(||
+			panic!("day {} ({}) gave no location: {:#}", day, solution.name, e));
+		assert_eq!((e.line, e.columns.clone()), (line, columns.clone()),
+			"day {} ({}): {}", day, solution.name, e);
+	}
+}
+
+#[test]
+fn d1() {
+	check(1, "199\n200\n2o8\n", 3, 0..3);
+}
+
+#[test]
+fn d2() {
+	check(2, "forward 5\ndown x5\n", 2, 5..7);
+	check(2, "forward 5\nsideways 5\n", 2, 0..8);
+	check(2, "forward 5\nup -9223372036854775808\n", 2, 3..23);
+}
+
+#[test]
+fn d3() {
+	check(3, "00100\n11110\n10120\n", 3, 3..4);
+}
+
+#[test]
+fn d4() {
+	check(4, "7,4,9\n\n1 2\n3 x\n", 4, 2..3);
+	// squares after more than one blank line still get read
+	check(4, "7,4,9\n\n1 2\n3 4\n\n\n5 6\n7 x\n", 8, 2..3);
+}
+
+#[test]
+fn d5() {
+	check(5, "0,9 -> 5,9\n8,0 -> 0,x8\n", 2, 9..11);
+	check(5, "0,9 -> 5,9\n0,0 -> 0,9999995\n", 2, 0..16);
+}
+
+#[test]
+fn d15() {
+	check(15, "1163\n1381\n2130\n", 3, 3..4);
+}
+
+#[test]
+fn d16() {
+	check(16, "8A004G801A8002F478\n", 1, 5..6);
+
+	// a chain of sums of one packet each, deep enough to run out of stack if
+	// it was scanned all the way down. the first one too deep starts at bit
+	// 18 * 101, in digit 454.
+	let mut bits = "000000100000000001".repeat(50_000);
+	bits += "00010000000";
+	while !bits.len().is_multiple_of(4) {
+		bits.push('0');
+	}
+	let hex: String = bits.as_bytes().chunks(4)
+		.map(|nibble| u32::from_str_radix(std::str::from_utf8(nibble).unwrap(), 2).unwrap())
+		.map(|n| char::from_digit(n, 16).unwrap())
+		.collect();
+	check(16, &hex, 1, 454..455);
+}
+
+#[test]
+fn d17() {
+	check(17, "target area: x=20..30, y=-10..-5x\n", 1, 30..33);
+	// past what the search can cover without overflowing
+	check(17, "target area: x=20..30, y=-50001..-5\n", 1, 25..31);
+	// the probe comes to a stop at x=0 and 3, then falls through y=0
+	check(17, "target area: x=-3..4, y=-5..2\n", 1, 13..29);
+	check(17, "target area: x=2..4, y=0..2\n", 1, 13..27);
+}
+
+#[test]
+fn d18() {
+	check(18, "[1,2]\n[[1,2];3]\n", 2, 6..7);
+	// inputs are reduced already
+	check(18, "[[[[[1,2],3],4],5],6]\n", 1, 4..5);
+	check(18, "[[1,23],4]\n", 1, 4..6);
+}
+
+#[test]
+fn d19() {
+	check(19, "--- scanner 0 ---\n404,-588,-901\n528,-643\n", 3, 0..8);
+}
+
+#[test]
+fn d24() {
+	check(24, "inp w\nadd x w\nadd q 1\n", 3, 4..5);
+}
+
+#[test]
+fn d25() {
+	check(25, "v...>>.vv>\n.vv>>.xv..\n", 2, 6..7);
+}
+
+#[test]
+fn rendering() {
+	let e = (DAYS[0].parse)("199\n200\n2o8\n").unwrap_err();
+	assert_eq!(e.to_string(), "\
+line 3, column 1: that's not a number: invalid digit found in string
+3 | 2o8
+  | ^^^");
+}
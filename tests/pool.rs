@@ -0,0 +1,32 @@
+use advent_of_code_2021::outcome::panic_message;
+use advent_of_code_2021::pool;
+
+#[test]
+fn in_order() {
+	let items: Vec<u64> = (0..100).collect();
+	let results = pool::map(&items, 4, |&n| n * n);
+	let squares: Vec<u64> = results.into_iter().map(Result::unwrap).collect();
+	assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+}
+
+// one item panicking shouldn't take the others down with it
+#[test]
+fn panics() {
+	let items: Vec<u64> = (0..10).collect();
+	let results = pool::map(&items, 3, |&n| {
+		if n == 4 {
+			panic!("no fours");
+		}
+		n
+	});
+
+	for (n, result) in items.iter().zip(results) {
+		match result {
+			Ok(r) => assert_eq!(r, *n),
+			Err(payload) => {
+				assert_eq!(*n, 4);
+				assert_eq!(panic_message(&*payload), "no fours");
+			}
+		}
+	}
+}
@@ -0,0 +1,44 @@
+use advent_of_code_2021::render::{self, Color, Options, Render, Viewport};
+
+// a diagonal of red xs on a plain background
+struct Diagonal(usize);
+
+impl Render for Diagonal {
+	fn size(&self) -> (usize, usize) {
+		(self.0, self.0)
+	}
+
+	fn cell(&self, x: usize, y: usize) -> (char, Option<Color>) {
+		match x == y {
+			true => ('x', Some(Color::Red)),
+			false => ('.', None),
+		}
+	}
+}
+
+fn options(color: bool, viewport: &str) -> Options {
+	Options {
+		color,
+		clear: false,
+		fps: 0.0,
+		viewport: match viewport {
+			"" => Viewport::default(),
+			v => v.parse().unwrap(),
+		},
+	}
+}
+
+#[test]
+fn draw() {
+	assert_eq!(render::draw(&Diagonal(3), &options(false, "")), "x..\n.x.\n..x\n");
+	assert_eq!(render::draw(&Diagonal(2), &options(true, "")),
+		"\x1b[31mx\x1b[0m.\n.\x1b[31mx\x1b[0m\n");
+	assert_eq!(render::draw(&Diagonal(5), &options(false, "2x2+1+2")),
+		".x\n..\n[2x2 of 5x5 at 1,2]\n");
+	assert_eq!(render::draw(&Diagonal(3), &options(false, "10x10")), "x..\n.x.\n..x\n");
+	assert_eq!(render::draw(&Diagonal(3), &options(false, &format!("{0}x{0}+1+1", usize::MAX))),
+		"x.\n.x\n[2x2 of 3x3 at 1,1]\n");
+
+	assert!("10".parse::<Viewport>().is_err());
+	assert!("10x10+1".parse::<Viewport>().is_err());
+}
@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use serde_json::{Value, json};
+
+use advent_of_code_2021::serve;
+
+fn responses(requests: &str) -> Vec<Value> {
+	let mut out = Vec::new();
+	serve::serve(requests.as_bytes(), &mut out, Some(Duration::from_secs(60))).unwrap();
+	String::from_utf8(out).unwrap().lines()
+		.map(|line| serde_json::from_str(line).unwrap())
+		.collect()
+}
+
+#[test]
+fn requests() {
+	let requests = [
+		json!({"id": 1, "day": 16, "part": 2, "input": "9C0141080250320F1802104A08\n"}),
+		json!({"id": 2, "day": 18, "impl": "string", "part": 1, "input": "[[1,2],[[3,4],5]]\n"}),
+		json!({"id": 3, "day": 3, "part": 1, "input": "0101\n01x1\n"}),
+		json!({"id": 4, "day": 16, "part": 3, "input": ""}),
+		json!({"id": 5, "day": 17, "part": 1, "input": "target area: x=20..30, y=-10..-5",
+			"timeout": 1e-9}),
+	];
+	let mut text: String = requests.iter().map(|r| format!("{}\n\n", r)).collect();
+	text += "{\"id\": 6, \"day\": \n";
+
+	let responses = responses(&text);
+	assert_eq!(responses.len(), 6);
+	let ids: Vec<_> = responses.iter().map(|r| r["id"].clone()).collect();
+	assert_eq!(ids, [json!(1), json!(2), json!(3), json!(4), json!(5), Value::Null]);
+
+	assert_eq!(responses[0]["answer"], "1");
+	assert_eq!(responses[0]["error"], Value::Null);
+	assert!(responses[0]["duration_ms"].is_f64());
+	assert_eq!(responses[1]["impl"], "string");
+	assert_eq!(responses[1]["answer"], "143");
+
+	let kinds: Vec<_> = responses[2..].iter().map(|r| r["error"]["kind"].clone()).collect();
+	assert_eq!(kinds, ["parse", "not_found", "timeout", "bad_request"]);
+	assert_eq!((&responses[2]["error"]["line"], &responses[2]["error"]["column"]),
+		(&json!(2), &json!(3)));
+	assert_eq!(responses[2]["answer"], Value::Null);
+}
@@ -0,0 +1,31 @@
+use advent_of_code_2021::vector::{M3, V2, V3, Vector};
+
+#[test]
+fn arithmetic() {
+	let a: V3 = "1,-2,3".parse().unwrap();
+	let b = Vector([4, 5, -6]);
+	assert_eq!(a + b, Vector([5, 3, -3]));
+	assert_eq!(a - b, Vector([-3, -7, 9]));
+	assert_eq!(-a * 2, Vector([-2, 4, -6]));
+	assert_eq!(a.manhattan_distance(b), 19);
+	assert_eq!(a.chebyshev_distance(b), 9);
+	assert_eq!((b - a).signum(), Vector([1, 1, -1]));
+	assert_eq!(a.to_string(), "1,-2,3");
+
+	assert!("1,2".parse::<V3>().is_err());
+	assert!("1,2,3".parse::<V2>().is_err());
+}
+
+#[test]
+fn matrices() {
+	let r: M3 = [
+		[0, -1, 0],
+		[1, 0, 0],
+		[0, 0, 1],
+	].into();
+	let v = Vector([1, 2, 3]);
+	assert_eq!(r * v, Vector([-2, 1, 3]));
+	assert_eq!(r * r * r * r, M3::identity());
+	assert_eq!((r * r) * v, r * (r * v));
+	assert_eq!(r.transpose() * r, M3::identity());
+}